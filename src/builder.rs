@@ -0,0 +1,264 @@
+//! Client configuration.
+
+use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Client as RClient, Proxy};
+use url::Url;
+
+use crate::environment::Environment;
+use crate::interceptor::{Interceptor, Interceptors};
+use crate::metrics::{MetricsSink, SharedSink};
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::retry::RetryPolicy;
+use crate::transport::{ReqwestTransport, SharedTransport, Transport};
+use crate::{Client, Error, Secret};
+
+/// Environment variable of the base URL for the Paddle API
+pub const ENV_API_URL: &str = "PADDLE_API_URL";
+/// Environment variable of the API key
+pub const ENV_API_AUTH: &str = "PADDLE_API_AUTH";
+
+/// `User-Agent` sent when none is set
+pub const DEFAULT_USER_AGENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Builder for a [`Client`].
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use paddle_api::ClientBuilder;
+///
+/// let client = ClientBuilder::new("https://sandbox-api.paddle.com/", "pdl_sdbx_apikey")
+///     .set_connect_timeout(Duration::from_secs(5))
+///     .set_timeout(Duration::from_secs(30))
+///     .set_user_agent("my-app/1.0")
+///     .set_paddle_version("1")
+///     .build()?;
+/// # Ok::<(), paddle_api::Error>(())
+/// ```
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct ClientBuilder {
+    environment: Result<Environment, url::ParseError>,
+    auth: Secret,
+    client: Option<RClient>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    user_agent: String,
+    paddle_version: Option<String>,
+    retry_policy: RetryPolicy,
+    rate_limit: Option<RateLimit>,
+    metrics: Option<SharedSink>,
+    interceptors: Interceptors,
+    transport: Option<SharedTransport>,
+}
+
+impl ClientBuilder {
+    /// ### Arguments
+    /// `url` - the base URL for the Paddle API\
+    /// `auth` - use Bearer authentication when making requests to the Paddle API
+    pub fn new<T: Into<Secret>>(url: &str, auth: T) -> Self {
+        Self::from_result(Url::parse(url).map(Environment::from_url), auth.into())
+    }
+
+    /// ### Arguments
+    /// `environment` - the Paddle environment\
+    /// `auth` - use Bearer authentication when making requests to the Paddle API
+    pub fn with_environment<T: Into<Secret>>(environment: Environment, auth: T) -> Self {
+        Self::from_result(Ok(environment), auth.into())
+    }
+
+    fn from_result(environment: Result<Environment, url::ParseError>, auth: Secret) -> Self {
+        Self {
+            environment,
+            auth,
+            client: None,
+            connect_timeout: None,
+            read_timeout: None,
+            timeout: None,
+            proxy: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            paddle_version: None,
+            retry_policy: RetryPolicy::none(),
+            rate_limit: None,
+            metrics: None,
+            interceptors: Interceptors::default(),
+            transport: None,
+        }
+    }
+
+    /// Read the base URL from `PADDLE_API_URL`
+    /// and the API key from `PADDLE_API_AUTH`.
+    pub fn from_env() -> Result<Self, Error> {
+        let var =
+            |key: &str| env::var(key).map_err(|e| Error::Validation(format!("{}: {}", key, e)));
+
+        Ok(Self::new(&var(ENV_API_URL)?, var(ENV_API_AUTH)?))
+    }
+
+    /// Send the requests with an existing `reqwest` client,
+    /// e.g. to share the connection pool of the application.
+    ///
+    /// Connect timeout, read timeout and proxy must be configured on that client.
+    pub fn set_client(self, client: RClient) -> Self {
+        Self {
+            client: Some(client),
+            ..self
+        }
+    }
+
+    /// Timeout for establishing a connection.
+    /// `Default: none`.
+    pub fn set_connect_timeout(self, timeout: Duration) -> Self {
+        Self {
+            connect_timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Timeout for each read from the connection, reset after every read,
+    /// so a slow but steady response isn't cut off.
+    /// `Default: none`.
+    pub fn set_read_timeout(self, timeout: Duration) -> Self {
+        Self {
+            read_timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Timeout for each attempt, from sending the request
+    /// until the response body is read.
+    /// `Default: none`.
+    pub fn set_timeout(self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Send the requests through the proxy.
+    pub fn set_proxy(self, proxy: Proxy) -> Self {
+        Self {
+            proxy: Some(proxy),
+            ..self
+        }
+    }
+
+    /// `Default: paddle_api/<version>`.
+    pub fn set_user_agent<T: Into<String>>(self, user_agent: T) -> Self {
+        Self {
+            user_agent: user_agent.into(),
+            ..self
+        }
+    }
+
+    /// Set the Paddle API version (only numbers)
+    pub fn set_paddle_version(self, version: &str) -> Self {
+        Self {
+            paddle_version: Some(version.to_string()),
+            ..self
+        }
+    }
+
+    /// Set the retry policy for failed requests.
+    /// `Default: no retries`.
+    pub fn set_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy,
+            ..self
+        }
+    }
+
+    /// Limit the requests of the client and its clones to the quota.
+    /// Every attempt, including retries, waits for the limiter.
+    /// `Default: no limit`.
+    pub fn set_rate_limit(self, rate_limit: RateLimit) -> Self {
+        Self {
+            rate_limit: Some(rate_limit),
+            ..self
+        }
+    }
+
+    /// Report the metrics of every request of the client and its clones.
+    pub fn set_metrics_sink<S: MetricsSink + 'static>(self, sink: S) -> Self {
+        Self {
+            metrics: Some(SharedSink::new(sink)),
+            ..self
+        }
+    }
+
+    /// Run the interceptor around every attempt of every request,
+    /// after the interceptors added before it.
+    pub fn add_interceptor<I: Interceptor + 'static>(mut self, interceptor: I) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+
+    /// Send the requests through the transport instead of the `reqwest` client.
+    ///
+    /// The connect timeout, read timeout and proxy only apply to the `reqwest` client.
+    pub fn set_transport<T: Transport + 'static>(self, transport: T) -> Self {
+        Self {
+            transport: Some(SharedTransport::new(transport)),
+            ..self
+        }
+    }
+
+    /// Fails when the API key belongs to the other Paddle environment.
+    pub fn build(self) -> Result<Client, Error> {
+        let environment = self.environment?;
+        environment.check_key(self.auth.expose())?;
+
+        let client = match self.client {
+            Some(_)
+                if self.connect_timeout.is_some()
+                    || self.read_timeout.is_some()
+                    || self.proxy.is_some() =>
+            {
+                return Err(Error::Validation(
+                    "connect timeout, read timeout and proxy can't be set with a custom reqwest client"
+                        .to_string(),
+                ))
+            }
+            Some(client) => client,
+            None => {
+                let mut builder = RClient::builder();
+
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+
+                if let Some(timeout) = self.read_timeout {
+                    builder = builder.read_timeout(timeout);
+                }
+
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+
+                builder.build()?
+            }
+        };
+
+        Ok(Client {
+            auth: self.auth,
+            url: environment.url(),
+            user_agent: self.user_agent,
+            timeout: self.timeout,
+            paddle_version: self.paddle_version,
+            retry_policy: self.retry_policy,
+            rate_limiter: self
+                .rate_limit
+                .map(|limit| Arc::new(RateLimiter::new(limit))),
+            metrics: self.metrics,
+            interceptors: self.interceptors,
+            transport: self
+                .transport
+                .unwrap_or_else(|| SharedTransport::new(ReqwestTransport::new(client.clone()))),
+            client,
+        })
+    }
+}
@@ -0,0 +1,334 @@
+//! Record and replay API interactions.
+//!
+//! A [`Cassette`] is a [`Transport`] that either records every request and
+//! response to a JSON file, or replays them from that file without a network.
+//!
+//! ```no_run
+//! use paddle_api::cassette::Cassette;
+//! use paddle_api::transport::ReqwestTransport;
+//! use paddle_api::Client;
+//!
+//! # fn main() -> Result<(), paddle_api::Error> {
+//! // Once, against the sandbox
+//! let transport = ReqwestTransport::new(reqwest::Client::new());
+//! let cassette = Cassette::record("tests/cassettes/products.json", transport)
+//!     .set_scrub_fields(["email"]);
+//! let client = Client::builder("https://sandbox-api.paddle.com/", "pdl_sdbx_apikey")
+//!     .set_transport(cassette)
+//!     .build()?;
+//!
+//! // In every test run
+//! let client = Client::builder("https://sandbox-api.paddle.com/", "pdl_sdbx_apikey")
+//!     .set_transport(Cassette::replay("tests/cassettes/products.json")?)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+use futures::future::BoxFuture;
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, CONTENT_ENCODING, CONTENT_LENGTH,
+    TRANSFER_ENCODING,
+};
+use reqwest::{Request, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+use crate::transport::{SharedTransport, Transport};
+use crate::util::write_atomic;
+use crate::Error;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Whether a [`Cassette`] sends the requests or answers them from the file.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub enum CassetteMode {
+    Record,
+    Replay,
+}
+
+/// Record/replay transport.
+///
+/// Requests are matched by method, path and query, ignoring the order of the
+/// query parameters. Each recorded interaction is replayed once, in order.
+/// In replay mode a request without a recorded interaction is an error.
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct Cassette {
+    path: PathBuf,
+    // `None` when replaying
+    transport: Option<SharedTransport>,
+    scrub_fields: Vec<String>,
+    scrub_headers: Vec<HeaderName>,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+struct Tape {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+    #[serde(skip)]
+    replayed: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    query: String,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+struct RecordedResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl Cassette {
+    /// Send the requests through `transport` and write every interaction to `path`,
+    /// replacing its previous content.
+    ///
+    /// The connect timeout, read timeout and proxy of the
+    /// [`ClientBuilder`](crate::ClientBuilder) don't apply to the cassette,
+    /// configure them on the `reqwest` client of the [`ReqwestTransport`].
+    pub fn record<P: Into<PathBuf>, T: Transport + 'static>(path: P, transport: T) -> Self {
+        Self {
+            path: path.into(),
+            transport: Some(SharedTransport::new(transport)),
+            scrub_fields: Vec::new(),
+            scrub_headers: vec![AUTHORIZATION],
+            interactions: Mutex::default(),
+        }
+    }
+
+    /// Answer the requests with the interactions recorded in `path`.
+    pub fn replay<P: Into<PathBuf>>(path: P) -> Result<Self, Error> {
+        let path = path.into();
+        let tape: Tape = serde_json::from_slice(&std::fs::read(&path)?).map_err(|e| {
+            Error::Validation(format!("invalid cassette {}: {}", path.display(), e))
+        })?;
+
+        Ok(Self {
+            path,
+            transport: None,
+            scrub_fields: Vec::new(),
+            scrub_headers: vec![AUTHORIZATION],
+            interactions: Mutex::new(tape.interactions),
+        })
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        match self.transport {
+            Some(_) => CassetteMode::Record,
+            None => CassetteMode::Replay,
+        }
+    }
+
+    /// Replace the values of these JSON fields, at any depth,
+    /// in the recorded request and response bodies.
+    pub fn set_scrub_fields<T, I>(self, fields: T) -> Self
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<String>,
+    {
+        Self {
+            scrub_fields: fields.into_iter().map(Into::into).collect(),
+            ..self
+        }
+    }
+
+    /// Replace the values of these headers in the recorded requests and responses.
+    /// `Authorization` is always scrubbed.
+    pub fn set_scrub_headers<T: IntoIterator<Item = HeaderName>>(self, headers: T) -> Self {
+        let mut scrub_headers = vec![AUTHORIZATION];
+        scrub_headers.extend(headers);
+
+        Self {
+            scrub_headers,
+            ..self
+        }
+    }
+
+    fn interactions(&self) -> MutexGuard<'_, Vec<Interaction>> {
+        self.interactions.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    async fn record_interaction(
+        &self,
+        transport: &SharedTransport,
+        request: Request,
+    ) -> Result<Response, Error> {
+        let recorded_request = RecordedRequest {
+            method: request.method().to_string(),
+            path: request.url().path().to_string(),
+            query: normalize_query(request.url()),
+            headers: self.scrub_headers(request.headers()),
+            body: request
+                .body()
+                .and_then(|b| b.as_bytes())
+                .map(|b| self.scrub_body(&String::from_utf8_lossy(b))),
+        };
+
+        let response = transport.execute(request).await?;
+        let status = response.status().as_u16();
+        let mut headers = response.headers().clone();
+        let body = response.text().await?;
+
+        // The body is stored decoded and may change length when scrubbed
+        headers.remove(CONTENT_LENGTH);
+        headers.remove(CONTENT_ENCODING);
+        headers.remove(TRANSFER_ENCODING);
+
+        let interaction = Interaction {
+            request: recorded_request,
+            response: RecordedResponse {
+                status,
+                headers: self.scrub_headers(&headers),
+                body: self.scrub_body(&body),
+            },
+            replayed: false,
+        };
+
+        let tape = {
+            let mut interactions = self.interactions();
+            interactions.push(interaction);
+            Tape {
+                interactions: interactions.clone(),
+            }
+        };
+        self.save(&tape)?;
+
+        // The caller gets the response as sent, not the scrubbed one
+        build_response(status, &headers, body)
+    }
+
+    fn replay_interaction(&self, request: &Request) -> Result<Response, Error> {
+        let method = request.method().as_str();
+        let path = request.url().path();
+        let query = normalize_query(request.url());
+
+        let mut interactions = self.interactions();
+        let interaction = interactions
+            .iter_mut()
+            .find(|i| {
+                !i.replayed
+                    && i.request.method == method
+                    && i.request.path == path
+                    && i.request.query == query
+            })
+            .ok_or_else(|| {
+                Error::Validation(format!(
+                    "no recorded interaction for {} {}?{} in cassette {}",
+                    method,
+                    path,
+                    query,
+                    self.path.display()
+                ))
+            })?;
+        interaction.replayed = true;
+
+        let response = &interaction.response;
+        let mut headers = HeaderMap::new();
+        for (name, value) in &response.headers {
+            let name = HeaderName::try_from(name.as_str())
+                .map_err(|e| Error::Validation(format!("invalid recorded header: {}", e)))?;
+            headers.append(name, HeaderValue::from_str(value)?);
+        }
+
+        build_response(response.status, &headers, response.body.clone())
+    }
+
+    fn save(&self, tape: &Tape) -> Result<(), Error> {
+        let json = serde_json::to_vec_pretty(tape)
+            .map_err(|e| Error::Validation(format!("can't serialize cassette: {}", e)))?;
+
+        Ok(write_atomic(&self.path, json)?)
+    }
+
+    // Every value of a repeated header, e.g. `Set-Cookie`, in order
+    fn scrub_headers(&self, headers: &HeaderMap) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if self.scrub_headers.contains(name) {
+                    REDACTED.to_string()
+                } else {
+                    String::from_utf8_lossy(value.as_bytes()).into_owned()
+                };
+                (name.to_string(), value)
+            })
+            .collect()
+    }
+
+    fn scrub_body(&self, body: &str) -> String {
+        match serde_json::from_str::<Value>(body) {
+            Ok(mut value) if !self.scrub_fields.is_empty() => {
+                scrub_value(&mut value, &self.scrub_fields);
+                value.to_string()
+            }
+            _ => body.to_string(),
+        }
+    }
+}
+
+impl Transport for Cassette {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        Box::pin(async move {
+            match &self.transport {
+                Some(transport) => self.record_interaction(transport, request).await,
+                None => self.replay_interaction(&request),
+            }
+        })
+    }
+}
+
+// Query pairs sorted by key, then value
+fn normalize_query(url: &Url) -> String {
+    let mut pairs: Vec<_> = url.query_pairs().collect();
+    pairs.sort();
+
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish()
+}
+
+fn scrub_value(value: &mut Value, fields: &[String]) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if fields.contains(key) {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    scrub_value(value, fields);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(|v| scrub_value(v, fields)),
+        _ => (),
+    }
+}
+
+fn build_response(status: u16, headers: &HeaderMap, body: String) -> Result<Response, Error> {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = reqwest::StatusCode::from_u16(status)
+        .map_err(|e| Error::Validation(format!("invalid recorded status: {}", e)))?;
+    *response.headers_mut() = headers.clone();
+
+    Ok(response.into())
+}
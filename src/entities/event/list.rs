@@ -0,0 +1,118 @@
+use crate::entities::Meta;
+use crate::id::EventId;
+use crate::webhook::event::WebhookEvent;
+use crate::{Client, Error};
+
+use serde::{Deserialize, Serialize};
+use serde_with::formats::CommaSeparator;
+use serde_with::{serde_as, StringWithSeparator};
+
+// https://developer.paddle.com/api-reference/events/list-events#query-parameters
+#[serde_as]
+#[derive(Serialize, Deserialize, Default)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct ListEventsParams {
+    after: Option<EventId>,
+    order_by: Option<String>,
+    per_page: Option<i32>,
+    #[serde_as(as = "Option<StringWithSeparator::<CommaSeparator, String>>")]
+    event_type: Option<Vec<String>>,
+}
+
+/// Events are the same objects as the webhooks sent for them,
+/// without a `notification_id`.
+// https://developer.paddle.com/api-reference/events/list-events#response
+#[derive(Deserialize)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct ListEventsResponse {
+    data: Vec<WebhookEvent>,
+    meta: Meta,
+}
+
+impl ListEventsParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return entities after the specified Paddle ID when working with paginated endpoints.
+    pub fn after(&self) -> Option<&EventId> {
+        self.after.as_ref()
+    }
+
+    /// Return entities after the specified Paddle ID when working with paginated endpoints.
+    pub fn set_after(mut self, after: EventId) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    pub fn order_by(&self) -> Option<&str> {
+        self.order_by.as_deref()
+    }
+
+    /// Order returned entities by the specified field and direction ([ASC] or [DESC]).
+    /// Valid fields for ordering: `id`.
+    pub fn set_order_by<T: Into<String>>(mut self, order_by: T) -> Self {
+        self.order_by = Some(order_by.into());
+        self
+    }
+
+    pub fn per_page(&self) -> Option<i32> {
+        self.per_page
+    }
+
+    /// Set how many entities are returned per page.\
+    /// `Default: 50; Maximum: 200`.
+    pub fn set_per_page<T: Into<i32>>(mut self, per_page: T) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn event_type(&self) -> Option<&[String]> {
+        self.event_type.as_deref()
+    }
+
+    /// Return entities that match the specified event types, for example `product.created`.
+    pub fn set_event_type<T, I>(mut self, event_type: T) -> Self
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<String>,
+    {
+        self.event_type = Some(event_type.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+impl ListEventsResponse {
+    pub fn data(&self) -> &[WebhookEvent] {
+        &self.data
+    }
+
+    pub fn meta(&self) -> &Meta {
+        &self.meta
+    }
+}
+
+impl From<ListEventsResponse> for (Vec<WebhookEvent>, Meta) {
+    fn from(r: ListEventsResponse) -> Self {
+        (r.data, r.meta)
+    }
+}
+
+impl Client {
+    /// List events that happened in your Paddle system (GET).
+    ///
+    /// Events are kept for 90 days.
+    ///
+    /// [Official document](https://developer.paddle.com/api-reference/events/list-events)
+    pub async fn get_list_events(
+        &self,
+        params: ListEventsParams,
+    ) -> Result<ListEventsResponse, Error> {
+        let query = serde_qs::to_string(&params)?;
+        let mut url = self.url.join("events")?;
+
+        url.set_query(Some(&query));
+
+        self.send(self.client.get(url)).await
+    }
+}
@@ -0,0 +1,16 @@
+use crate::entities::ip_addresses::IpAddressesResponse;
+use crate::{Client, Error};
+
+impl Client {
+    /// Get the IP addresses that Paddle uses to send webhooks (GET).
+    ///
+    /// Use [`IpAddresses::contains`](super::IpAddresses::contains) to reject
+    /// webhook requests from other sources.
+    ///
+    /// [Official document](https://developer.paddle.com/api-reference/ip-addresses/get-ip-addresses)
+    pub async fn get_ip_addresses(&self) -> Result<IpAddressesResponse, Error> {
+        let url = self.url.join("ips")?;
+
+        self.send(self.client.get(url)).await
+    }
+}
@@ -0,0 +1,65 @@
+pub mod get;
+
+use std::net::IpAddr;
+
+use super::Meta;
+
+use ipnet::Ipv4Net;
+use serde::Deserialize;
+
+/// The response to a successful get IP addresses request.
+// https://developer.paddle.com/api-reference/ip-addresses/get-ip-addresses#response
+#[derive(Deserialize)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct IpAddressesResponse {
+    data: IpAddresses,
+    meta: Meta,
+}
+
+/// IP address ranges that Paddle uses to send webhooks.
+/// Use them to allowlist incoming webhook requests.
+// https://developer.paddle.com/api-reference/ip-addresses/overview
+#[derive(Deserialize)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct IpAddresses {
+    ipv4_cidrs: Vec<Ipv4Net>,
+}
+
+impl IpAddressesResponse {
+    pub fn data(&self) -> &IpAddresses {
+        &self.data
+    }
+
+    pub fn meta(&self) -> &Meta {
+        &self.meta
+    }
+}
+
+impl IpAddresses {
+    /// List of Paddle IPv4 CIDR blocks.
+    pub fn ipv4_cidrs(&self) -> &[Ipv4Net] {
+        &self.ipv4_cidrs
+    }
+
+    /// Check whether the address belongs to one of the Paddle CIDR blocks.
+    ///
+    /// IPv4-mapped IPv6 addresses (`::ffff:a.b.c.d`) are checked as IPv4,
+    /// any other IPv6 address is rejected.
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        let ip = match ip {
+            IpAddr::V4(ip) => *ip,
+            IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+                Some(ip) => ip,
+                None => return false,
+            },
+        };
+
+        self.ipv4_cidrs.iter().any(|net| net.contains(&ip))
+    }
+}
+
+impl From<IpAddressesResponse> for (IpAddresses, Meta) {
+    fn from(response: IpAddressesResponse) -> (IpAddresses, Meta) {
+        (response.data, response.meta)
+    }
+}
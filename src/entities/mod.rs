@@ -1,18 +1,26 @@
+pub mod event;
+pub mod ip_addresses;
 pub mod price;
 pub mod product;
 
+use std::fmt::Display;
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use serde_with::formats::CommaSeparator;
-use serde_with::{serde_as, StringWithSeparator};
+use serde_with::{serde_as, DisplayFromStr, StringWithSeparator};
+
+use crate::timestamp::Timestamp;
 
 /// The base entity object contains common attributes for all entities.
 pub trait EntityBaseGettersSetters {
+    /// Paddle ID of the entity type
+    type Id;
+
     /// Unique Paddle ID
-    fn id(&self) -> Option<&str>;
+    fn id(&self) -> Option<&Self::Id>;
     /// Name of the entity
     fn name(&self) -> Option<&str>;
 
@@ -36,27 +44,27 @@ pub trait EntityBaseGettersSetters {
     fn set_custom_data(self, custom_data: serde_json::Value) -> Self;
     /// Import meta of the entity
     fn import_meta(&self) -> Option<&serde_json::Value>;
-    /// Set the import meta of the entity
-    fn created_at(&self) -> Option<&str>;
-    /// Set the created at of the entity
-    fn updated_at(&self) -> Option<&str>;
+    /// When the entity was created
+    fn created_at(&self) -> Option<&Timestamp>;
+    /// When the entity was last updated
+    fn updated_at(&self) -> Option<&Timestamp>;
 }
 
 /// The base list params object contains common attributes for all list params.
 pub trait BaseListParamsGettersSetters {
+    /// Paddle ID of the listed entity type
+    type Id;
+
     /// Return entities after the specified Paddle ID when working with paginated endpoints.
-    fn after(&self) -> Option<&str>;
+    fn after(&self) -> Option<&Self::Id>;
     /// Set the after of the list params
     /// Return entities after the specified Paddle ID when working with paginated endpoints.
     /// Used in the `meta.pagination.next` URL in responses for list operations.
-    fn set_after<T: Into<String>>(self, after: T) -> Self;
+    fn set_after(self, after: Self::Id) -> Self;
     /// Return entities with the specified Paddle IDs.
-    fn id(&self) -> Option<&Vec<String>>;
+    fn id(&self) -> Option<&[Self::Id]>;
     /// Set the id of the list params
-    fn set_id<T, I>(self, id: T) -> Self
-    where
-        T: IntoIterator<Item = I>,
-        I: Into<String>;
+    fn set_id<T: IntoIterator<Item = Self::Id>>(self, id: T) -> Self;
     /// Order returned entities by the specified field and direction ([ASC] or [DESC]).
     fn order_by(&self) -> Option<&str>;
     /// Order returned entities by the specified field and direction ([ASC] or [DESC]).
@@ -101,10 +109,10 @@ pub trait BaseListParamsGettersSetters {
 
 /// The base entity object contains common attributes for all entities.
 #[skip_serializing_none]
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize)]
 #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
-pub struct EntityBase {
-    id: Option<String>,
+pub struct EntityBase<Id> {
+    id: Option<Id>,
     name: Option<String>,
     description: Option<String>,
     #[serde(rename = "type")]
@@ -112,18 +120,23 @@ pub struct EntityBase {
     status: Option<EntityStatus>,
     custom_data: Option<serde_json::Value>,
     import_meta: Option<serde_json::Value>,
-    created_at: Option<String>,
-    updated_at: Option<String>,
+    created_at: Option<Timestamp>,
+    updated_at: Option<Timestamp>,
 }
 
 /// The base list params object contains common attributes for all list params.
 #[serde_as]
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Clone)]
 #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
-pub struct BaseListParams {
-    after: Option<String>,
-    #[serde_as(as = "Option<StringWithSeparator::<CommaSeparator, String>>")]
-    id: Option<Vec<String>>,
+#[serde(bound(
+    serialize = "Id: Display",
+    deserialize = "Id: FromStr, Id::Err: Display"
+))]
+pub struct BaseListParams<Id> {
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    after: Option<Id>,
+    #[serde_as(as = "Option<StringWithSeparator::<CommaSeparator, Id>>")]
+    id: Option<Vec<Id>>,
     order_by: Option<String>,
     per_page: Option<i32>,
     #[serde_as(as = "Option<StringWithSeparator::<CommaSeparator, String>>")]
@@ -134,7 +147,7 @@ pub struct BaseListParams {
     p_type: Option<EntityType>,
 }
 
-#[derive(Serialize, Deserialize, Default, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
 #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
 pub enum EntityStatus {
     /// Entity is active and can be used.
@@ -148,7 +161,7 @@ pub enum EntityStatus {
 
 /// Type of item. Standard items are considered part of your catalog
 /// and are shown on the Paddle web app.
-#[derive(Serialize, Deserialize, Default, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
 #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
 pub enum EntityType {
     /// Non-catalog item. Typically created for a specific transaction or\
@@ -179,6 +192,37 @@ pub struct Pagination {
     pub estimated_total: i32,
 }
 
+// Manual impls, the derives would require `Id: Default`
+impl<Id> Default for EntityBase<Id> {
+    fn default() -> Self {
+        Self {
+            id: None,
+            name: None,
+            description: None,
+            p_type: None,
+            status: None,
+            custom_data: None,
+            import_meta: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+impl<Id> Default for BaseListParams<Id> {
+    fn default() -> Self {
+        Self {
+            after: None,
+            id: None,
+            order_by: None,
+            per_page: None,
+            include: None,
+            status: None,
+            p_type: None,
+        }
+    }
+}
+
 impl Meta {
     pub fn request_id(&self) -> &str {
         &self.request_id
@@ -186,13 +230,13 @@ impl Meta {
 }
 
 impl FromStr for EntityStatus {
-    type Err = anyhow::Error;
+    type Err = crate::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "active" => Ok(Self::Active),
             "archived" => Ok(Self::Archived),
-            _ => Err(anyhow::anyhow!("Invalid EntityStatus")),
+            _ => Err(crate::Error::Validation("Invalid EntityStatus".to_string())),
         }
     }
 }
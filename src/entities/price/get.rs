@@ -1,6 +1,6 @@
 use crate::entities::{price::PriceResponse, BaseListParamsGettersSetters};
-use crate::error::PaddleError;
-use crate::Client;
+use crate::id::PriceId;
+use crate::{Client, Error};
 
 use super::list::ListPricesParams;
 
@@ -8,9 +8,9 @@ impl Client {
     /// Get a single price by its ID (GET).
     pub async fn get_price<T, I>(
         &self,
-        id: &str,
+        id: &PriceId,
         include: Option<T>,
-    ) -> Result<PriceResponse, anyhow::Error>
+    ) -> Result<PriceResponse, Error>
     where
         T: IntoIterator<Item = I>,
         I: Into<String>,
@@ -24,17 +24,6 @@ impl Client {
             )?));
         }
 
-        let response = PaddleError::handle_response(
-            self.client
-                .get(url)
-                .headers(self.default_headers()?)
-                .send()
-                .await?,
-        )
-        .await?
-        .json()
-        .await?;
-
-        Ok(response)
+        self.send(self.client.get(url)).await
     }
 }
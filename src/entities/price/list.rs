@@ -1,17 +1,18 @@
 use crate::entities::price::Price;
-use crate::Client;
-use crate::{
-    entities::{BaseListParams, BaseListParamsGettersSetters, EntityStatus, EntityType, Meta},
-    error::PaddleError,
+use crate::entities::{
+    BaseListParams, BaseListParamsGettersSetters, EntityStatus, EntityType, Meta,
 };
+use crate::id::PriceId;
+use crate::pagination::ListStream;
+use crate::{Client, Error};
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
 pub struct ListPricesParams {
     #[serde(flatten)]
-    base: BaseListParams,
+    base: BaseListParams<PriceId>,
     recurring: Option<bool>,
 }
 
@@ -30,32 +31,30 @@ pub struct PriceResponseFromList {
 }
 
 impl BaseListParamsGettersSetters for ListPricesParams {
-    fn after(&self) -> Option<&str> {
-        self.base.after.as_deref()
+    type Id = PriceId;
+
+    fn after(&self) -> Option<&Self::Id> {
+        self.base.after.as_ref()
     }
 
-    fn set_after<T: Into<String>>(self, after: T) -> Self {
+    fn set_after(self, after: Self::Id) -> Self {
         Self {
             base: BaseListParams {
-                after: Some(after.into()),
+                after: Some(after),
                 ..self.base
             },
             ..self
         }
     }
 
-    fn id(&self) -> Option<&Vec<String>> {
-        self.base.id.as_ref()
+    fn id(&self) -> Option<&[Self::Id]> {
+        self.base.id.as_deref()
     }
 
-    fn set_id<T, I>(self, id: T) -> Self
-    where
-        T: IntoIterator<Item = I>,
-        I: Into<String>,
-    {
+    fn set_id<T: IntoIterator<Item = Self::Id>>(self, id: T) -> Self {
         Self {
             base: BaseListParams {
-                id: Some(id.into_iter().map(Into::into).collect()),
+                id: Some(id.into_iter().collect()),
                 ..self.base
             },
             ..self
@@ -177,21 +176,36 @@ impl Client {
     pub async fn get_list_prices(
         &self,
         params: ListPricesParams,
-    ) -> Result<ListPricesResponse, anyhow::Error> {
+    ) -> Result<ListPricesResponse, Error> {
         let query = serde_qs::to_string(&params)?;
         let mut url = self.url.join("prices")?;
 
         url.set_query(Some(&query));
 
-        Ok(PaddleError::handle_response(
-            self.client
-                .get(url)
-                .headers(self.default_headers()?)
-                .send()
-                .await?,
-        )
-        .await?
-        .json()
-        .await?)
+        self.send(self.client.get(url)).await
+    }
+
+    /// Stream all prices matching the parameters,
+    /// requesting the next page when the previous one is consumed.
+    ///
+    /// ### Arguments
+    /// `params` - list prices parameters of the first page
+    pub fn stream_prices(&self, params: ListPricesParams) -> ListStream<'_, Price> {
+        ListStream::new(params, move |params| async move {
+            let (data, meta) = self.get_list_prices(params).await?.into();
+            Ok((data.into_iter().map(Into::into).collect(), meta))
+        })
+    }
+}
+
+impl From<ListPricesResponse> for (Vec<PriceResponseFromList>, Meta) {
+    fn from(r: ListPricesResponse) -> Self {
+        (r.data, r.meta)
+    }
+}
+
+impl From<PriceResponseFromList> for Price {
+    fn from(p: PriceResponseFromList) -> Self {
+        p.price
     }
 }
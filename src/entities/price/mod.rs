@@ -6,6 +6,8 @@ pub mod update;
 use super::{
     product::Product, EntityBase, EntityBaseGettersSetters, EntityStatus, EntityType, Meta,
 };
+use crate::id::{PriceId, ProductId};
+use crate::timestamp::Timestamp;
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -22,8 +24,8 @@ pub struct PriceResponse {
 #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
 pub struct Price {
     #[serde(flatten)]
-    base: EntityBase,
-    product_id: Option<String>,
+    base: EntityBase<PriceId>,
+    product_id: Option<ProductId>,
     unit_price: Option<UnitPrice>,
     billing_cycle: Option<BillingCycle>,
     trial_period: Option<TrialPeriod>,
@@ -111,8 +113,10 @@ impl PriceResponse {
 }
 
 impl EntityBaseGettersSetters for Price {
-    fn id(&self) -> Option<&str> {
-        self.base.id.as_deref()
+    type Id = PriceId;
+
+    fn id(&self) -> Option<&PriceId> {
+        self.base.id.as_ref()
     }
 
     fn name(&self) -> Option<&str> {
@@ -189,22 +193,22 @@ impl EntityBaseGettersSetters for Price {
         self.base.import_meta.as_ref()
     }
 
-    fn created_at(&self) -> Option<&str> {
-        self.base.created_at.as_deref()
+    fn created_at(&self) -> Option<&Timestamp> {
+        self.base.created_at.as_ref()
     }
 
-    fn updated_at(&self) -> Option<&str> {
-        self.base.updated_at.as_deref()
+    fn updated_at(&self) -> Option<&Timestamp> {
+        self.base.updated_at.as_ref()
     }
 }
 
 impl Price {
-    pub fn product_id(&self) -> Option<&str> {
-        self.product_id.as_deref()
+    pub fn product_id(&self) -> Option<&ProductId> {
+        self.product_id.as_ref()
     }
 
-    pub fn set_product_id<T: Into<String>>(mut self, product_id: T) -> Self {
-        self.product_id = Some(product_id.into());
+    pub fn set_product_id(mut self, product_id: ProductId) -> Self {
+        self.product_id = Some(product_id);
         self
     }
 
@@ -1,6 +1,6 @@
 use crate::entities::price::PriceResponse;
-use crate::error::PaddleError;
-use crate::Client;
+use crate::id::PriceId;
+use crate::{Client, Error};
 
 use super::Price;
 
@@ -12,23 +12,11 @@ impl Client {
     /// [Official document](https://developer.paddle.com/api-reference/prices/update-price)
     pub async fn update_price(
         &self,
-        price_id: &str,
+        price_id: &PriceId,
         price_data: Price,
-    ) -> Result<PriceResponse, anyhow::Error> {
+    ) -> Result<PriceResponse, Error> {
         let url = self.url.join(&format!("prices/{}", price_id))?;
 
-        let response = PaddleError::handle_response(
-            self.client
-                .patch(url)
-                .headers(self.default_headers()?)
-                .json(&price_data)
-                .send()
-                .await?,
-        )
-        .await?
-        .json()
-        .await?;
-
-        Ok(response)
+        self.send(self.client.patch(url).json(&price_data)).await
     }
 }
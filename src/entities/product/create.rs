@@ -1,6 +1,5 @@
 use crate::entities::product::ProductResponse;
-use crate::error::PaddleError;
-use crate::Client;
+use crate::{Client, Error};
 
 use super::Product;
 
@@ -14,24 +13,9 @@ impl Client {
     /// If successful, your response includes a copy of the new product entity.
     ///
     /// [Official document](https://developer.paddle.com/api-reference/products/create-product)
-    pub async fn create_product(
-        &self,
-        product_data: Product,
-    ) -> Result<ProductResponse, anyhow::Error> {
+    pub async fn create_product(&self, product_data: Product) -> Result<ProductResponse, Error> {
         let url = self.url.join("products")?;
 
-        let response = PaddleError::handle_response(
-            self.client
-                .post(url)
-                .headers(self.default_headers()?)
-                .json(&product_data)
-                .send()
-                .await?,
-        )
-        .await?
-        .json()
-        .await?;
-
-        Ok(response)
+        self.send(self.client.post(url).json(&product_data)).await
     }
 }
@@ -1,7 +1,7 @@
 use crate::entities::product::{list::ListProductsParams, ProductResponse};
 use crate::entities::BaseListParamsGettersSetters;
-use crate::error::PaddleError;
-use crate::Client;
+use crate::id::ProductId;
+use crate::{Client, Error};
 
 impl Client {
     /// Get a single product by its ID (GET).
@@ -9,9 +9,9 @@ impl Client {
     /// [Official document](https://developer.paddle.com/api-reference/products/get-product)
     pub async fn get_product<T, I>(
         &self,
-        id: &str,
+        id: &ProductId,
         include: Option<T>,
-    ) -> Result<ProductResponse, anyhow::Error>
+    ) -> Result<ProductResponse, Error>
     where
         T: IntoIterator<Item = I>,
         I: Into<String>,
@@ -25,17 +25,6 @@ impl Client {
             )?));
         }
 
-        let response = PaddleError::handle_response(
-            self.client
-                .get(url)
-                .headers(self.default_headers()?)
-                .send()
-                .await?,
-        )
-        .await?
-        .json()
-        .await?;
-
-        Ok(response)
+        self.send(self.client.get(url)).await
     }
 }
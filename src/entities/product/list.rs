@@ -3,8 +3,9 @@ use std::str::FromStr;
 use crate::entities::{
     BaseListParams, BaseListParamsGettersSetters, EntityStatus, EntityType, Meta,
 };
-use crate::error::PaddleError;
-use crate::Client;
+use crate::id::ProductId;
+use crate::pagination::ListStream;
+use crate::{Client, Error};
 
 use super::{Product, ProductTaxCategory};
 
@@ -15,11 +16,11 @@ use serde_with::{serde_as, StringWithSeparator};
 
 // https://developer.paddle.com/api-reference/products/list-products#query-parameters
 #[serde_as]
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
 pub struct ListProductsParams {
     #[serde(flatten)]
-    base: BaseListParams,
+    base: BaseListParams<ProductId>,
     #[serde_as(as = "Option<StringWithSeparator::<CommaSeparator, ProductTaxCategory>>")]
     tax_category: Option<Vec<ProductTaxCategory>>,
 }
@@ -40,32 +41,30 @@ pub struct ProductResponseFromList {
 }
 
 impl BaseListParamsGettersSetters for ListProductsParams {
-    fn after(&self) -> Option<&str> {
-        self.base.after.as_deref()
+    type Id = ProductId;
+
+    fn after(&self) -> Option<&Self::Id> {
+        self.base.after.as_ref()
     }
 
-    fn set_after<T: Into<String>>(self, after: T) -> Self {
+    fn set_after(self, after: Self::Id) -> Self {
         Self {
             base: BaseListParams {
-                after: Some(after.into()),
+                after: Some(after),
                 ..self.base
             },
             ..self
         }
     }
 
-    fn id(&self) -> Option<&Vec<String>> {
-        self.base.id.as_ref()
+    fn id(&self) -> Option<&[Self::Id]> {
+        self.base.id.as_deref()
     }
 
-    fn set_id<T, I>(self, id: T) -> Self
-    where
-        T: IntoIterator<Item = I>,
-        I: Into<String>,
-    {
+    fn set_id<T: IntoIterator<Item = Self::Id>>(self, id: T) -> Self {
         Self {
             base: BaseListParams {
-                id: Some(id.into_iter().map(Into::into).collect()),
+                id: Some(id.into_iter().collect()),
                 ..self.base
             },
             ..self
@@ -197,22 +196,25 @@ impl Client {
     pub async fn get_list_products(
         &self,
         params: ListProductsParams,
-    ) -> Result<ListProductsResponse, anyhow::Error> {
+    ) -> Result<ListProductsResponse, Error> {
         let query = serde_qs::to_string(&params)?;
         let mut url = self.url.join("products")?;
 
         url.set_query(Some(&query));
 
-        Ok(PaddleError::handle_response(
-            self.client
-                .get(url)
-                .headers(self.default_headers()?)
-                .send()
-                .await?,
-        )
-        .await?
-        .json()
-        .await?)
+        self.send(self.client.get(url)).await
+    }
+
+    /// Stream all products matching the parameters,
+    /// requesting the next page when the previous one is consumed.
+    ///
+    /// ### Arguments
+    /// `params` - list products parameters of the first page
+    pub fn stream_products(&self, params: ListProductsParams) -> ListStream<'_, Product> {
+        ListStream::new(params, move |params| async move {
+            let (data, meta) = self.get_list_products(params).await?.into();
+            Ok((data.into_iter().map(Into::into).collect(), meta))
+        })
     }
 }
 
@@ -5,6 +5,8 @@ pub mod update;
 
 use super::{EntityBase, EntityBaseGettersSetters, EntityStatus, EntityType, Meta};
 use crate::entities::price::Price;
+use crate::id::ProductId;
+use crate::timestamp::Timestamp;
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -25,7 +27,7 @@ pub struct ProductResponse {
 #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
 pub struct Product {
     #[serde(flatten)]
-    base: EntityBase,
+    base: EntityBase<ProductId>,
     tax_category: Option<ProductTaxCategory>,
     image_url: Option<String>,
     prices: Option<Vec<Price>>,
@@ -34,7 +36,7 @@ pub struct Product {
 /// Tax category for this product. Used for charging the correct rate of tax.
 /// Selected tax category must be enabled on your Paddle account.
 /// [Official document](https://developer.paddle.com/api-reference/products/list-products)
-#[derive(Serialize, Deserialize, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
 pub enum ProductTaxCategory {
     #[serde(rename = "digital-goods")]
@@ -68,8 +70,10 @@ impl ProductResponse {
 }
 
 impl EntityBaseGettersSetters for Product {
-    fn id(&self) -> Option<&str> {
-        self.base.id.as_deref()
+    type Id = ProductId;
+
+    fn id(&self) -> Option<&ProductId> {
+        self.base.id.as_ref()
     }
 
     fn name(&self) -> Option<&str> {
@@ -146,12 +150,12 @@ impl EntityBaseGettersSetters for Product {
         self.base.import_meta.as_ref()
     }
 
-    fn created_at(&self) -> Option<&str> {
-        self.base.created_at.as_deref()
+    fn created_at(&self) -> Option<&Timestamp> {
+        self.base.created_at.as_ref()
     }
 
-    fn updated_at(&self) -> Option<&str> {
-        self.base.updated_at.as_deref()
+    fn updated_at(&self) -> Option<&Timestamp> {
+        self.base.updated_at.as_ref()
     }
 }
 
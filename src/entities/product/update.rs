@@ -1,6 +1,6 @@
 use crate::entities::product::ProductResponse;
-use crate::error::PaddleError;
-use crate::Client;
+use crate::id::ProductId;
+use crate::{Client, Error};
 
 use super::Product;
 
@@ -12,23 +12,11 @@ impl Client {
     /// [Official document](https://developer.paddle.com/api-reference/products/update-product)
     pub async fn update_product(
         &self,
-        product_id: &str,
+        product_id: &ProductId,
         product_data: Product,
-    ) -> Result<ProductResponse, anyhow::Error> {
+    ) -> Result<ProductResponse, Error> {
         let url = self.url.join(&format!("products/{}", product_id))?;
 
-        let response = PaddleError::handle_response(
-            self.client
-                .patch(url)
-                .headers(self.default_headers()?)
-                .json(&product_data)
-                .send()
-                .await?,
-        )
-        .await?
-        .json::<ProductResponse>()
-        .await?;
-
-        Ok(response)
+        self.send(self.client.patch(url).json(&product_data)).await
     }
 }
@@ -0,0 +1,104 @@
+//! Paddle environments.
+//!
+//! [Official document](https://developer.paddle.com/api-reference/about/paddle-api#base-url)
+
+use std::fmt;
+use std::str::FromStr;
+
+use url::Url;
+
+use crate::Error;
+
+const SANDBOX_URL: &str = "https://sandbox-api.paddle.com/";
+const PRODUCTION_URL: &str = "https://api.paddle.com/";
+
+const SANDBOX_KEY_PREFIX: &str = "pdl_sdbx_";
+const PRODUCTION_KEY_PREFIX: &str = "pdl_live_";
+
+/// Environment the client sends the requests to.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub enum Environment {
+    /// `https://sandbox-api.paddle.com/`
+    Sandbox,
+    /// `https://api.paddle.com/`
+    Production,
+    /// Any other base URL, e.g. a mock server
+    Custom(Url),
+}
+
+impl Environment {
+    /// Recognize the Paddle base URLs, any other URL is [`Environment::Custom`].
+    pub fn from_url(url: Url) -> Self {
+        let url = normalize(url);
+
+        if url.as_str() == SANDBOX_URL {
+            Self::Sandbox
+        } else if url.as_str() == PRODUCTION_URL {
+            Self::Production
+        } else {
+            Self::Custom(url)
+        }
+    }
+
+    /// Base URL ending with `/`, so that `Url::join` keeps every path segment.
+    pub fn url(&self) -> Url {
+        match self {
+            Self::Sandbox => Url::parse(SANDBOX_URL).unwrap(),
+            Self::Production => Url::parse(PRODUCTION_URL).unwrap(),
+            Self::Custom(url) => normalize(url.clone()),
+        }
+    }
+
+    /// Refuse a sandbox key in production and a live key in the sandbox.
+    /// Keys without a known prefix and custom environments are not checked.
+    pub fn check_key(&self, auth: &str) -> Result<(), Error> {
+        let wrong_prefix = match self {
+            Self::Sandbox => PRODUCTION_KEY_PREFIX,
+            Self::Production => SANDBOX_KEY_PREFIX,
+            Self::Custom(_) => return Ok(()),
+        };
+
+        if auth.starts_with(wrong_prefix) {
+            return Err(Error::Validation(format!(
+                "API key with the `{}` prefix can't be used in {}",
+                wrong_prefix, self
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Environment {
+    type Err = Error;
+
+    /// `sandbox`, `production` or a base URL
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sandbox" => Ok(Self::Sandbox),
+            "production" => Ok(Self::Production),
+            url => Ok(Self::from_url(Url::parse(url)?)),
+        }
+    }
+}
+
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sandbox => write!(f, "sandbox"),
+            Self::Production => write!(f, "production"),
+            Self::Custom(url) => write!(f, "{}", url),
+        }
+    }
+}
+
+// Append the missing trailing slash of the path
+fn normalize(mut url: Url) -> Url {
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+
+    url
+}
@@ -1,8 +1,52 @@
 use crate::entities::Meta;
-use reqwest::Response;
+use crate::retry::DEFAULT_RETRYABLE_STATUSES;
+use reqwest::header::HeaderMap;
+use reqwest::{Response, StatusCode};
 
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 
+/// Errors returned by the [`Client`](crate::Client).
+#[derive(Debug)]
+pub enum Error {
+    /// Paddle responded with an error.
+    ///
+    /// [Official document](https://developer.paddle.com/api-reference/about/errors)
+    Api {
+        status: StatusCode,
+        error: Box<ErrorDetail>,
+        request_id: Option<String>,
+    },
+    /// Error response that isn't a Paddle error envelope,
+    /// for example an HTML page from a proxy.
+    UnexpectedResponse {
+        status: StatusCode,
+        headers: HeaderMap,
+        /// Raw response body, truncated to [`MAX_ERROR_BODY`] bytes including a trailing `…`
+        body: String,
+    },
+    /// The request couldn't be sent or the response couldn't be read.
+    Http(reqwest::Error),
+    /// The response body doesn't match the expected type.
+    Decode {
+        /// Raw response body
+        body: String,
+        /// Path of the field that failed, for example `data.prices[0].unit_price`
+        path: String,
+        source: serde_json::Error,
+    },
+    /// The request URL couldn't be built.
+    InvalidUrl(url::ParseError),
+    /// Invalid request parameters or unexpected response content.
+    Validation(String),
+    /// Local storage failed, for example a checkpoint file.
+    Io(std::io::Error),
+}
+
+/// Maximum length of the raw body kept in [`Error::UnexpectedResponse`].
+pub const MAX_ERROR_BODY: usize = 1024;
+
+/// Error response of the Paddle API.
 #[derive(Debug, Deserialize)]
 pub struct PaddleError {
     pub error: ErrorDetail,
@@ -13,18 +57,317 @@ pub struct PaddleError {
 pub struct ErrorDetail {
     #[serde(rename = "type")]
     pub e_type: String,
-    pub code: String,
+    pub code: ErrorCode,
     pub detail: String,
     pub documentation_url: String,
     pub errors: Option<Vec<ValidationError>>,
 }
 
+/// Stable error codes documented by Paddle.
+///
+/// Codes unknown to this crate are kept in [`ErrorCode::Other`].
+///
+/// [Official document](https://developer.paddle.com/errors/overview)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum ErrorCode {
+    /// `bad_request` - the request is invalid.
+    BadRequest,
+    /// `authentication_missing` - the `Authorization` header is missing.
+    AuthenticationMissing,
+    /// `authentication_malformed` - the `Authorization` header is malformed.
+    AuthenticationMalformed,
+    /// `invalid_token` - the API key is invalid or revoked.
+    InvalidToken,
+    /// `forbidden` - the API key doesn't have permission for this operation.
+    Forbidden,
+    /// `not_found` - the entity doesn't exist.
+    NotFound,
+    /// `entity_archived` - the entity is archived and can't be changed.
+    EntityArchived,
+    /// `method_not_allowed` - the HTTP method isn't supported by the endpoint.
+    MethodNotAllowed,
+    /// `conflict` - the request conflicts with the current state of the entity.
+    Conflict,
+    /// `concurrent_modification` - the entity was changed by another request.
+    ConcurrentModification,
+    /// `invalid_field` - one or more fields didn't pass validation.
+    InvalidField,
+    /// `invalid_json` - the body isn't valid JSON.
+    InvalidJson,
+    /// `invalid_url` - the URL is invalid.
+    InvalidUrl,
+    /// `unsupported_media_type` - the `Content-Type` isn't supported.
+    UnsupportedMediaType,
+    /// `request_body_too_large` - the body is too large.
+    RequestBodyTooLarge,
+    /// `too_many_requests` - the rate limit was exceeded.
+    TooManyRequests,
+    /// `internal_error` - Paddle failed to process the request.
+    InternalError,
+    /// `bad_gateway` - an upstream service failed.
+    BadGateway,
+    /// `service_unavailable` - Paddle is temporarily unavailable.
+    ServiceUnavailable,
+    /// `not_implemented` - the operation isn't supported.
+    NotImplemented,
+    /// Any other code
+    Other(String),
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ValidationError {
     pub field: String,
     pub message: String,
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Api { status, error, .. } => {
+                write!(
+                    f,
+                    "Paddle API error {} ({}): {}",
+                    status, error.code, error.detail
+                )
+            }
+            Self::UnexpectedResponse { status, body, .. } => {
+                write!(f, "unexpected response {}: {}", status, body)
+            }
+            Self::Http(e) => write!(f, "HTTP error: {}", e),
+            Self::Decode { path, source, .. } => {
+                write!(f, "failed to decode response at `{}`: {}", path, source)
+            }
+            Self::InvalidUrl(e) => write!(f, "invalid URL: {}", e),
+            Self::Validation(e) => write!(f, "validation error: {}", e),
+            Self::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Http(e) => Some(e),
+            Self::Decode { source, .. } => Some(source),
+            Self::InvalidUrl(e) => Some(e),
+            Self::Io(e) => Some(e),
+            Self::Api { .. } | Self::UnexpectedResponse { .. } | Self::Validation(_) => None,
+        }
+    }
+}
+
+impl Error {
+    /// Details of the Paddle error response.
+    pub fn api_error(&self) -> Option<&ErrorDetail> {
+        match self {
+            Self::Api { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+
+    /// HTTP status of the Paddle error response.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Api { status, .. } | Self::UnexpectedResponse { status, .. } => Some(*status),
+            Self::Http(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    /// Paddle error code.
+    pub fn code(&self) -> Option<&ErrorCode> {
+        self.api_error().map(|e| &e.code)
+    }
+
+    /// The same request may succeed later: rate limits, server errors and timeouts.
+    ///
+    /// Matches the statuses retried by the default [`RetryPolicy`](crate::retry::RetryPolicy).
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Api { status, error, .. } => {
+                error.code.is_retryable() || DEFAULT_RETRYABLE_STATUSES.contains(status)
+            }
+            Self::UnexpectedResponse { status, .. } => DEFAULT_RETRYABLE_STATUSES.contains(status),
+            Self::Http(e) => e.is_timeout() || e.is_connect(),
+            _ => false,
+        }
+    }
+
+    /// The API key is missing, invalid or lacks permissions.
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            Self::Api { status, error, .. } => {
+                error.code.is_auth_error()
+                    || *status == StatusCode::UNAUTHORIZED
+                    || *status == StatusCode::FORBIDDEN
+            }
+            Self::UnexpectedResponse { status, .. } => {
+                *status == StatusCode::UNAUTHORIZED || *status == StatusCode::FORBIDDEN
+            }
+            _ => false,
+        }
+    }
+
+    /// The entity doesn't exist.
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Self::Api { status, error, .. } => {
+                error.code.is_not_found() || *status == StatusCode::NOT_FOUND
+            }
+            Self::UnexpectedResponse { status, .. } => *status == StatusCode::NOT_FOUND,
+            _ => false,
+        }
+    }
+
+    /// Paddle request ID, include it when contacting Paddle support.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::Api { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Deserialize a response body, reporting the path of the field that failed.
+    pub(crate) fn decode<T: DeserializeOwned>(body: String) -> Result<T, Self> {
+        let result =
+            serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_str(&body));
+
+        result.map_err(|e| Self::Decode {
+            path: e.path().to_string(),
+            source: e.into_inner(),
+            body,
+        })
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Self::Http(e)
+    }
+}
+
+impl From<url::ParseError> for Error {
+    fn from(e: url::ParseError) -> Self {
+        Self::InvalidUrl(e)
+    }
+}
+
+impl From<serde_qs::Error> for Error {
+    fn from(e: serde_qs::Error) -> Self {
+        Self::Validation(format!("invalid query parameters: {}", e))
+    }
+}
+
+impl From<reqwest::header::InvalidHeaderValue> for Error {
+    fn from(e: reqwest::header::InvalidHeaderValue) -> Self {
+        Self::Validation(format!("invalid header value: {}", e))
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::BadRequest => "bad_request",
+            Self::AuthenticationMissing => "authentication_missing",
+            Self::AuthenticationMalformed => "authentication_malformed",
+            Self::InvalidToken => "invalid_token",
+            Self::Forbidden => "forbidden",
+            Self::NotFound => "not_found",
+            Self::EntityArchived => "entity_archived",
+            Self::MethodNotAllowed => "method_not_allowed",
+            Self::Conflict => "conflict",
+            Self::ConcurrentModification => "concurrent_modification",
+            Self::InvalidField => "invalid_field",
+            Self::InvalidJson => "invalid_json",
+            Self::InvalidUrl => "invalid_url",
+            Self::UnsupportedMediaType => "unsupported_media_type",
+            Self::RequestBodyTooLarge => "request_body_too_large",
+            Self::TooManyRequests => "too_many_requests",
+            Self::InternalError => "internal_error",
+            Self::BadGateway => "bad_gateway",
+            Self::ServiceUnavailable => "service_unavailable",
+            Self::NotImplemented => "not_implemented",
+            Self::Other(code) => code,
+        }
+    }
+
+    /// The same request may succeed later.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::TooManyRequests
+                | Self::InternalError
+                | Self::BadGateway
+                | Self::ServiceUnavailable
+        )
+    }
+
+    /// The API key is missing, invalid or lacks permissions.
+    pub fn is_auth_error(&self) -> bool {
+        matches!(
+            self,
+            Self::AuthenticationMissing
+                | Self::AuthenticationMalformed
+                | Self::InvalidToken
+                | Self::Forbidden
+        )
+    }
+
+    /// The entity doesn't exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound)
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(code: String) -> Self {
+        match code.as_str() {
+            "bad_request" => Self::BadRequest,
+            "authentication_missing" => Self::AuthenticationMissing,
+            "authentication_malformed" => Self::AuthenticationMalformed,
+            "invalid_token" => Self::InvalidToken,
+            "forbidden" => Self::Forbidden,
+            "not_found" => Self::NotFound,
+            "entity_archived" => Self::EntityArchived,
+            "method_not_allowed" => Self::MethodNotAllowed,
+            "conflict" => Self::Conflict,
+            "concurrent_modification" => Self::ConcurrentModification,
+            "invalid_field" => Self::InvalidField,
+            "invalid_json" => Self::InvalidJson,
+            "invalid_url" => Self::InvalidUrl,
+            "unsupported_media_type" => Self::UnsupportedMediaType,
+            "request_body_too_large" => Self::RequestBodyTooLarge,
+            "too_many_requests" => Self::TooManyRequests,
+            "internal_error" => Self::InternalError,
+            "bad_gateway" => Self::BadGateway,
+            "service_unavailable" => Self::ServiceUnavailable,
+            "not_implemented" => Self::NotImplemented,
+            _ => Self::Other(code),
+        }
+    }
+}
+
+impl std::str::FromStr for ErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 impl std::fmt::Display for PaddleError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:#?}", self)?;
@@ -35,12 +378,40 @@ impl std::fmt::Display for PaddleError {
 impl std::error::Error for PaddleError {}
 
 impl PaddleError {
-    pub async fn handle_response(response: Response) -> Result<Response, anyhow::Error> {
+    /// Turn a Paddle error response into [`Error::Api`],
+    /// or [`Error::UnexpectedResponse`] if the body isn't a Paddle error.
+    pub async fn handle_response(response: Response) -> Result<Response, Error> {
         let status = response.status();
         if status.is_client_error() || status.is_server_error() {
-            let error = response.json::<PaddleError>().await?;
-            return Err(anyhow::anyhow!("{}", error));
+            let headers = response.headers().clone();
+            let body = response.text().await?;
+
+            return Err(match serde_json::from_str::<PaddleError>(&body) {
+                Ok(error) => Error::Api {
+                    status,
+                    error: Box::new(error.error),
+                    request_id: Some(error.meta.request_id),
+                },
+                Err(_) => Error::UnexpectedResponse {
+                    status,
+                    headers,
+                    body: truncate(body, MAX_ERROR_BODY),
+                },
+            });
         }
         Ok(response)
     }
 }
+
+// Cut the body at a char boundary, leaving room for the ellipsis
+fn truncate(mut body: String, max: usize) -> String {
+    if body.len() > max {
+        let mut end = max - '…'.len_utf8();
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        body.truncate(end);
+        body.push('…');
+    }
+    body
+}
@@ -0,0 +1,209 @@
+//! Typed Paddle IDs.
+//!
+//! Every Paddle ID starts with a prefix of its entity type,
+//! followed by `_` and lowercase letters and digits:
+//! `pro_01gsz4t5hdjse780zja8vvr7jg`.
+//!
+//! [Official document](https://developer.paddle.com/api-reference/about/paddle-ids)
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+macro_rules! paddle_ids {
+    ($( $(#[$doc:meta])* $name:ident($kind:ident) = $prefix:literal, )*) => {
+        /// Entity type of a Paddle ID.
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        pub enum IdKind {
+            $( $kind, )*
+        }
+
+        impl IdKind {
+            /// Prefix of the IDs without `_`, for example `pro`.
+            pub fn prefix(&self) -> &'static str {
+                match self {
+                    $( Self::$kind => $prefix, )*
+                }
+            }
+
+            fn from_prefix(prefix: &str) -> Option<Self> {
+                match prefix {
+                    $( $prefix => Some(Self::$kind), )*
+                    _ => None,
+                }
+            }
+        }
+
+        $(
+            $(#[$doc])*
+            #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+            #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+            #[serde(try_from = "String", into = "String")]
+            pub struct $name(String);
+
+            impl $name {
+                pub const KIND: IdKind = IdKind::$kind;
+
+                pub fn as_str(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl FromStr for $name {
+                type Err = InvalidId;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Self::try_from(s.to_string())
+                }
+            }
+
+            impl TryFrom<String> for $name {
+                type Error = InvalidId;
+
+                fn try_from(id: String) -> Result<Self, Self::Error> {
+                    match id_kind(&id) {
+                        Some(IdKind::$kind) => Ok(Self(id)),
+                        _ => Err(InvalidId {
+                            id,
+                            expected: IdKind::$kind,
+                        }),
+                    }
+                }
+            }
+
+            impl TryFrom<&str> for $name {
+                type Error = InvalidId;
+
+                fn try_from(id: &str) -> Result<Self, Self::Error> {
+                    id.parse()
+                }
+            }
+
+            impl From<$name> for String {
+                fn from(id: $name) -> Self {
+                    id.0
+                }
+            }
+
+            impl AsRef<str> for $name {
+                fn as_ref(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl PartialEq<str> for $name {
+                fn eq(&self, other: &str) -> bool {
+                    self.0 == other
+                }
+            }
+
+            impl PartialEq<&str> for $name {
+                fn eq(&self, other: &&str) -> bool {
+                    self.0 == *other
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(&self.0)
+                }
+            }
+        )*
+    };
+}
+
+paddle_ids! {
+    /// `add_`
+    AddressId(Address) = "add",
+    /// `adj_`
+    AdjustmentId(Adjustment) = "adj",
+    /// `biz_`
+    BusinessId(Business) = "biz",
+    /// `ctm_`
+    CustomerId(Customer) = "ctm",
+    /// `dsc_`
+    DiscountId(Discount) = "dsc",
+    /// `evt_`
+    EventId(Event) = "evt",
+    /// `ntf_`
+    NotificationId(Notification) = "ntf",
+    /// `ntfset_`
+    NotificationSettingId(NotificationSetting) = "ntfset",
+    /// `paymtd_`
+    PaymentMethodId(PaymentMethod) = "paymtd",
+    /// `pri_`
+    PriceId(Price) = "pri",
+    /// `pro_`
+    ProductId(Product) = "pro",
+    /// `sub_`
+    SubscriptionId(Subscription) = "sub",
+    /// `txn_`
+    TransactionId(Transaction) = "txn",
+}
+
+/// Entity type of an arbitrary ID, `None` when it isn't a Paddle ID.
+///
+/// ```
+/// use paddle_api::id::{id_kind, IdKind};
+///
+/// assert_eq!(id_kind("pri_01gsz8x8sawmvhz1pv30nge1ke"), Some(IdKind::Price));
+/// assert_eq!(id_kind("01gsz8x8sawmvhz1pv30nge1ke"), None);
+/// ```
+pub fn id_kind(id: &str) -> Option<IdKind> {
+    let (prefix, rest) = id.split_once('_')?;
+    let valid = !rest.is_empty()
+        && rest
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit());
+
+    valid.then(|| IdKind::from_prefix(prefix)).flatten()
+}
+
+impl fmt::Display for IdKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_", self.prefix())
+    }
+}
+
+/// ID without the expected prefix or with invalid characters.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InvalidId {
+    id: String,
+    expected: IdKind,
+}
+
+impl InvalidId {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn expected(&self) -> IdKind {
+        self.expected
+    }
+}
+
+impl fmt::Display for InvalidId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match id_kind(&self.id) {
+            Some(kind) => write!(
+                f,
+                "expected a `{}` ID, got a `{}` ID: {}",
+                self.expected, kind, self.id
+            ),
+            None => write!(
+                f,
+                "expected a `{}` ID, got an invalid ID: {}",
+                self.expected, self.id
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvalidId {}
+
+impl From<InvalidId> for crate::Error {
+    fn from(e: InvalidId) -> Self {
+        Self::Validation(e.to_string())
+    }
+}
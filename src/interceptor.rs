@@ -0,0 +1,51 @@
+//! Request interceptors.
+//!
+//! Interceptors registered with
+//! [`ClientBuilder::add_interceptor`](crate::ClientBuilder::add_interceptor)
+//! see every attempt of every request, in the order they were added.
+
+use std::sync::Arc;
+
+use reqwest::{Request, Response};
+
+use crate::util::Shared;
+
+/// Hooks around each attempt of a request.
+///
+/// ```
+/// use paddle_api::interceptor::Interceptor;
+/// use reqwest::header::HeaderValue;
+///
+/// struct CorrelationId(HeaderValue);
+///
+/// impl Interceptor for CorrelationId {
+///     fn before_request(&self, request: &mut reqwest::Request) {
+///         request.headers_mut().insert("X-Correlation-Id", self.0.clone());
+///     }
+/// }
+/// ```
+pub trait Interceptor: Send + Sync {
+    /// Called before the request is sent, after the default headers are set.
+    fn before_request(&self, _request: &mut Request) {}
+
+    /// Called when a response is received, before its body is read.
+    fn after_response(&self, _response: &Response) {}
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub(crate) struct Interceptors(Vec<Shared<dyn Interceptor>>);
+
+impl Interceptors {
+    pub(crate) fn push<I: Interceptor + 'static>(&mut self, interceptor: I) {
+        self.0.push(Shared(Arc::new(interceptor)));
+    }
+
+    pub(crate) fn before_request(&self, request: &mut Request) {
+        self.0.iter().for_each(|i| i.before_request(request));
+    }
+
+    pub(crate) fn after_response(&self, response: &Response) {
+        self.0.iter().for_each(|i| i.after_response(response));
+    }
+}
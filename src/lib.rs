@@ -1,19 +1,64 @@
+pub mod builder;
+pub mod cassette;
 pub mod entities;
+pub mod environment;
 pub mod error;
+pub mod id;
+pub mod interceptor;
+#[cfg(feature = "logs")]
+mod logs;
+pub mod metrics;
+#[cfg(feature = "mock-server")]
+pub mod mock;
+pub mod money;
+pub mod pagination;
+pub mod poller;
+pub mod rate_limit;
+pub mod retry;
+pub mod secret;
+pub mod timestamp;
+pub mod transport;
+mod util;
+pub mod webhook;
+
+pub use crate::builder::ClientBuilder;
+pub use crate::environment::Environment;
+pub use crate::error::Error;
+pub use crate::secret::Secret;
 
 use crate::error::PaddleError;
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
-use reqwest::Client as RClient;
+use crate::interceptor::Interceptors;
+use crate::metrics::{RequestMetrics, SharedSink};
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
+use crate::transport::SharedTransport;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
+use reqwest::{Client as RClient, Request, RequestBuilder, StatusCode};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use url::Url;
+use zeroize::Zeroizing;
 
 /// Paddle API client
+///
+/// Clones share the connection pool and the rate limiter.
+#[derive(Clone)]
 #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
 pub struct Client {
+    // Builds the requests, which are sent through `transport`
     client: RClient,
-    auth: String,
+    auth: Secret,
     url: Url,
+    user_agent: String,
+    timeout: Option<Duration>,
     paddle_version: Option<String>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    metrics: Option<SharedSink>,
+    interceptors: Interceptors,
+    transport: SharedTransport,
 }
 
 impl Client {
@@ -23,13 +68,35 @@ impl Client {
     /// `url` - the base URL for the Paddle API\
     /// `auth` - use Bearer authentication when making requests to the Paddle API
     // https://developer.paddle.com/api-reference/about/authentication
-    pub fn new<T: Into<String>>(url: &str, auth: T) -> Result<Self, anyhow::Error> {
-        Ok(Self {
-            client: RClient::new(),
-            auth: auth.into(),
-            url: Url::parse(url)?,
-            paddle_version: None,
-        })
+    pub fn new<T: Into<Secret>>(url: &str, auth: T) -> Result<Self, Error> {
+        Self::builder(url, auth).build()
+    }
+
+    /// Create a new Paddle API client for the environment
+    ///
+    /// ### Arguments
+    /// `environment` - the Paddle environment\
+    /// `auth` - use Bearer authentication when making requests to the Paddle API
+    pub fn with_environment<T: Into<Secret>>(
+        environment: Environment,
+        auth: T,
+    ) -> Result<Self, Error> {
+        ClientBuilder::with_environment(environment, auth).build()
+    }
+
+    /// Configure a new Paddle API client
+    ///
+    /// ### Arguments
+    /// `url` - the base URL for the Paddle API\
+    /// `auth` - use Bearer authentication when making requests to the Paddle API
+    pub fn builder<T: Into<Secret>>(url: &str, auth: T) -> ClientBuilder {
+        ClientBuilder::new(url, auth)
+    }
+
+    /// Create a new Paddle API client from the `PADDLE_API_URL`
+    /// and `PADDLE_API_AUTH` environment variables
+    pub fn from_env() -> Result<Self, Error> {
+        ClientBuilder::from_env()?.build()
     }
 
     /*
@@ -42,16 +109,26 @@ impl Client {
         self.paddle_version = Some(version.to_string());
     }
 
+    /// Number of requests that can be sent right now without waiting,
+    /// `None` without a rate limit.
+    pub fn rate_limit_budget(&self) -> Option<u32> {
+        self.rate_limiter
+            .as_ref()
+            .map(|limiter| limiter.available())
+    }
+
     /// Default headers for Paddle API requests
-    fn default_headers(&self) -> Result<HeaderMap, anyhow::Error> {
+    fn default_headers(&self) -> Result<HeaderMap, Error> {
         let mut headers = HeaderMap::new();
 
         headers.insert(CONTENT_TYPE, HeaderValue::from_str("application/json")?);
+        headers.insert(USER_AGENT, HeaderValue::from_str(&self.user_agent)?);
 
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", self.auth))?,
-        );
+        // Sensitive values are left out of the `Debug` output of reqwest
+        let bearer = Zeroizing::new(format!("Bearer {}", self.auth.expose()));
+        let mut authorization = HeaderValue::from_str(&bearer)?;
+        authorization.set_sensitive(true);
+        headers.insert(AUTHORIZATION, authorization);
 
         if let Some(version) = &self.paddle_version {
             headers.insert("Paddle-Version", HeaderValue::from_str(version)?);
@@ -66,44 +143,140 @@ impl Client {
     /// `403 Forbidden` will be returned if the authentication fails.
     ///
     /// [Official document](https://developer.paddle.com/api-reference/about/authentication#test-authentication)
-    pub async fn test_authentication(&self) -> Result<(), anyhow::Error> {
+    pub async fn test_authentication(&self) -> Result<(), Error> {
         Self::include_data_meta(
-            PaddleError::handle_response(
-                self.client
-                    .get(self.url.join("event-types")?)
-                    .headers(self.default_headers()?)
-                    .send()
-                    .await?,
-            )
-            .await?
-            .text()
-            .await?,
+            self.send(self.client.get(self.url.join("event-types")?))
+                .await?,
         )
     }
 
+    /// Send the request with the default headers
+    /// and deserialize the successful response.
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, Error> {
+        let mut request = request.headers(self.default_headers()?);
+
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
+        let request = request.build()?;
+
+        #[cfg(feature = "logs")]
+        let span = logs::request_span(&self.url, &request);
+
+        let response = self.execute(request);
+
+        #[cfg(feature = "logs")]
+        let response = tracing::Instrument::instrument(response, span);
+
+        response.await
+    }
+
+    /// Execute the request and report its metrics.
+    async fn execute<T: DeserializeOwned>(&self, request: Request) -> Result<T, Error> {
+        let started = Instant::now();
+        let (result, status, attempt) = self.attempts(&request).await;
+
+        if let Some(metrics) = &self.metrics {
+            let endpoint = metrics::endpoint(&self.url, request.url());
+            metrics.record(&RequestMetrics::new(
+                request.method(),
+                &endpoint,
+                status,
+                started.elapsed(),
+                attempt - 1,
+            ));
+        }
+
+        result.and_then(Error::decode)
+    }
+
+    /// Send the request, retrying it according to the retry policy.
+    /// Returns the body, the last status and the number of attempts.
+    async fn attempts(
+        &self,
+        request: &Request,
+    ) -> (Result<String, Error>, Option<StatusCode>, u32) {
+        let mut attempt = 1;
+
+        loop {
+            // JSON bodies are buffered, so the request can always be cloned
+            let Some(mut attempt_request) = request.try_clone() else {
+                let error = Error::Validation("request body can't be retried".to_string());
+                return (Err(error), None, attempt);
+            };
+
+            if let Some(limiter) = &self.rate_limiter {
+                limiter.acquire().await;
+            }
+
+            self.interceptors.before_request(&mut attempt_request);
+
+            #[cfg(feature = "logs")]
+            let started = Instant::now();
+
+            let (error, retry_after) = match self.transport.execute(attempt_request).await {
+                Ok(response) => {
+                    self.interceptors.after_response(&response);
+
+                    let status = response.status();
+                    let retry_after = retry::retry_after(response.headers());
+                    match PaddleError::handle_response(response).await {
+                        Ok(response) => {
+                            let body = response.text().await.map_err(Error::from);
+
+                            #[cfg(feature = "logs")]
+                            if let Ok(body) = &body {
+                                logs::record_response(attempt, status, started, body);
+                            }
+
+                            return (body, Some(status), attempt);
+                        }
+                        Err(e) => (e, retry_after),
+                    }
+                }
+                Err(e) => (e, None),
+            };
+
+            #[cfg(feature = "logs")]
+            logs::record_error(attempt, &error, started);
+
+            match self
+                .retry_policy
+                .next_delay(request.method(), attempt, &error, retry_after)
+            {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => {
+                    let status = error.status();
+                    return (Err(error), status, attempt);
+                }
+            }
+
+            attempt += 1;
+        }
+    }
+
     // Response
     //
     // If successful, you should get
     // a response that includes a `data` array and a `meta` object.
     //
     // https://developer.paddle.com/api-reference/about/authentication#test-authentication-response
-    fn include_data_meta(body: String) -> Result<(), anyhow::Error> {
-        let v: Value = serde_json::from_str(&body)?;
-
+    fn include_data_meta(v: Value) -> Result<(), Error> {
         if let Some(data) = v.get("data") {
             if let Some(array) = data.as_array() {
                 if array.is_empty() {
-                    return Err(anyhow::anyhow!("Data is empty"));
+                    return Err(Error::Validation("Data is empty".to_string()));
                 }
             } else {
-                return Err(anyhow::anyhow!("Data is not a collection"));
+                return Err(Error::Validation("Data is not a collection".to_string()));
             }
         } else {
-            return Err(anyhow::anyhow!("Data is missing"));
+            return Err(Error::Validation("Data is missing".to_string()));
         }
 
         if v.get("meta").is_none() {
-            return Err(anyhow::anyhow!("Meta is missing"));
+            return Err(Error::Validation("Meta is missing".to_string()));
         }
 
         Ok(())
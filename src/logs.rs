@@ -0,0 +1,97 @@
+//! Tracing instrumentation, enabled by the `logs` feature.
+//!
+//! Every request runs in a `paddle.request` span with the method, the path
+//! and the entity type. The status, latency, attempt number and Paddle's
+//! `request_id` are recorded after each attempt. Headers, query strings and
+//! bodies are never recorded, so the API key and customer data stay out of traces.
+
+use std::time::Instant;
+
+use reqwest::{Request, StatusCode};
+use serde::Deserialize;
+use tracing::field::Empty;
+use tracing::Span;
+use url::Url;
+
+use crate::Error;
+
+#[derive(Deserialize)]
+struct Body {
+    meta: Option<BodyMeta>,
+}
+
+#[derive(Deserialize)]
+struct BodyMeta {
+    request_id: String,
+}
+
+/// Span of a request, including its retries.
+pub(crate) fn request_span(base: &Url, request: &Request) -> Span {
+    tracing::info_span!(
+        "paddle.request",
+        http.request.method = %request.method(),
+        url.path = request.url().path(),
+        paddle.entity = entity(base, request.url()),
+        http.response.status_code = Empty,
+        latency_ms = Empty,
+        attempt = Empty,
+        paddle.request_id = Empty,
+    )
+}
+
+/// Record a successful attempt on the current request span.
+pub(crate) fn record_response(attempt: u32, status: StatusCode, started: Instant, body: &str) {
+    let request_id = serde_json::from_str::<Body>(body)
+        .ok()
+        .and_then(|body| body.meta)
+        .map(|meta| meta.request_id);
+
+    let span = record(attempt, Some(status), started, request_id.as_deref());
+    span.in_scope(|| tracing::debug!(attempt, status = status.as_u16(), "paddle response"));
+}
+
+/// Record a failed attempt on the current request span.
+pub(crate) fn record_error(attempt: u32, error: &Error, started: Instant) {
+    let span = record(attempt, error.status(), started, error.request_id());
+    let code = error.code().map(|code| code.as_str());
+
+    // Only the kind of error, details may echo request values
+    span.in_scope(|| {
+        tracing::warn!(
+            attempt,
+            status = error.status().map(|s| s.as_u16()),
+            code,
+            "paddle request failed"
+        )
+    });
+}
+
+fn record(
+    attempt: u32,
+    status: Option<StatusCode>,
+    started: Instant,
+    request_id: Option<&str>,
+) -> Span {
+    let span = Span::current();
+
+    span.record("attempt", attempt);
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+
+    if let Some(status) = status {
+        span.record("http.response.status_code", status.as_u16());
+    }
+
+    if let Some(request_id) = request_id {
+        span.record("paddle.request_id", request_id);
+    }
+
+    span
+}
+
+// First path segment after the base URL, e.g. `products`
+fn entity<'a>(base: &Url, url: &'a Url) -> &'a str {
+    url.path()
+        .strip_prefix(base.path())
+        .and_then(|path| path.split('/').next())
+        .unwrap_or_default()
+}
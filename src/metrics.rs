@@ -0,0 +1,144 @@
+//! Request metrics.
+//!
+//! A [`MetricsSink`] set on the [`Client`](crate::Client) is called once per
+//! request, after the last attempt.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+use url::Url;
+
+use crate::util::Shared;
+
+/// Receiver of the metrics of every request.
+pub trait MetricsSink: Send + Sync {
+    fn record(&self, metrics: &RequestMetrics<'_>);
+}
+
+/// Metrics of a request, including its retries.
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct RequestMetrics<'a> {
+    method: &'a Method,
+    endpoint: &'a str,
+    status: Option<StatusCode>,
+    duration: Duration,
+    retries: u32,
+}
+
+impl<'a> RequestMetrics<'a> {
+    pub(crate) fn new(
+        method: &'a Method,
+        endpoint: &'a str,
+        status: Option<StatusCode>,
+        duration: Duration,
+        retries: u32,
+    ) -> Self {
+        Self {
+            method,
+            endpoint,
+            status,
+            duration,
+            retries,
+        }
+    }
+
+    pub fn method(&self) -> &Method {
+        self.method
+    }
+
+    /// Path relative to the base URL with the IDs replaced, e.g. `prices/{id}`
+    pub fn endpoint(&self) -> &str {
+        self.endpoint
+    }
+
+    /// Status of the last attempt, `None` when no response was received
+    pub fn status(&self) -> Option<StatusCode> {
+        self.status
+    }
+
+    /// Time from the first attempt until the last response,
+    /// including the retry delays
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+}
+
+pub(crate) type SharedSink = Shared<dyn MetricsSink>;
+
+impl SharedSink {
+    pub(crate) fn new<S: MetricsSink + 'static>(sink: S) -> Self {
+        Shared(Arc::new(sink))
+    }
+}
+
+/// Endpoint template of the URL.
+///
+/// Paddle IDs always contain `_` (`pri_01gsz8x8sawmvhz1pv30nge1ke`),
+/// unlike entity names and actions (`event-types`, `cancel`).
+pub(crate) fn endpoint(base: &Url, url: &Url) -> String {
+    let path = url.path();
+    let path = path.strip_prefix(base.path()).unwrap_or(path);
+
+    path.split('/')
+        .map(|segment| {
+            if segment.contains('_') {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// [`MetricsSink`] of the `metrics` crate.
+///
+/// - `paddle_api_requests_total` counter by `method`, `endpoint` and `status`
+/// - `paddle_api_request_duration_seconds` histogram by `method` and `endpoint`
+/// - `paddle_api_retries_total` counter by `method` and `endpoint`
+///
+/// `status` is `error` when no response was received.
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct MetricsRecorder;
+
+#[cfg(feature = "metrics")]
+impl MetricsSink for MetricsRecorder {
+    fn record(&self, metrics: &RequestMetrics<'_>) {
+        let method = metrics.method().to_string();
+        let endpoint = metrics.endpoint().to_string();
+        let status = metrics
+            .status()
+            .map_or_else(|| "error".to_string(), |s| s.as_u16().to_string());
+
+        ::metrics::counter!(
+            "paddle_api_requests_total",
+            "method" => method.clone(),
+            "endpoint" => endpoint.clone(),
+            "status" => status
+        )
+        .increment(1);
+
+        ::metrics::histogram!(
+            "paddle_api_request_duration_seconds",
+            "method" => method.clone(),
+            "endpoint" => endpoint.clone()
+        )
+        .record(metrics.duration().as_secs_f64());
+
+        if metrics.retries() > 0 {
+            ::metrics::counter!(
+                "paddle_api_retries_total",
+                "method" => method,
+                "endpoint" => endpoint
+            )
+            .increment(metrics.retries() as u64);
+        }
+    }
+}
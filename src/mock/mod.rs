@@ -0,0 +1,386 @@
+//! In-process mock of the Paddle API, enabled by the `mock-server` feature.
+//!
+//! [`MockServer`] serves the product and price endpoints from memory on a
+//! local port, so [`Client`](crate::Client) runs its real code paths offline.
+//!
+//! - IDs are generated with the entity prefix (`pro_`, `pri_`)
+//! - lists support `id`, `status`, `type`, `order_by`, `include`,
+//!   `after` and `per_page`
+//! - errors use Paddle's error envelope
+//!
+//! ```
+//! use paddle_api::entities::{product::Product, EntityBaseGettersSetters};
+//! use paddle_api::mock::MockServer;
+//! use paddle_api::Client;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mock = MockServer::start().await?;
+//! let client = Client::new(&mock.url(), "pdl_sdbx_apikey")?;
+//!
+//! let r = client.create_product(Product::default().set_name("Pro")).await;
+//! assert!(r.is_err()); // tax_category is required
+//! # Ok(())
+//! # }
+//! ```
+
+mod prices;
+mod products;
+
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde_json::{json, Map, Value};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+const DEFAULT_PER_PAGE: usize = 50;
+const MAX_PER_PAGE: usize = 200;
+
+/// Local HTTP server implementing the product and price endpoints.
+///
+/// The server stops when the value is dropped.
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct MockServer {
+    addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Start the server on a free port of `127.0.0.1`.
+    pub async fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        let state = Arc::new(Mock {
+            url: format!("http://{}/", addr),
+            store: Mutex::default(),
+        });
+
+        let app = Router::new()
+            .route("/products", get(products::list).post(products::create))
+            .route("/products/{id}", get(products::get).patch(products::update))
+            .route("/prices", get(prices::list).post(prices::create))
+            .route("/prices/{id}", get(prices::get).patch(prices::update))
+            .fallback(|| async { ApiError::not_found("route") })
+            .with_state(state);
+
+        let task = tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+
+        Ok(Self { addr, task })
+    }
+
+    /// Base URL for [`Client::new`](crate::Client::new)
+    pub fn url(&self) -> String {
+        format!("http://{}/", self.addr)
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+type Shared = Arc<Mock>;
+type Params = HashMap<String, String>;
+
+struct Mock {
+    url: String,
+    store: Mutex<Store>,
+}
+
+impl Mock {
+    fn store(&self) -> MutexGuard<'_, Store> {
+        self.store.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[derive(Default)]
+struct Store {
+    products: BTreeMap<String, Value>,
+    prices: BTreeMap<String, Value>,
+    sequence: u64,
+}
+
+impl Store {
+    // Sortable IDs with 26 characters after the prefix, like Paddle's
+    fn next_id(&mut self, prefix: &str) -> String {
+        self.sequence += 1;
+        format!("{}_01{:024}", prefix, self.sequence)
+    }
+}
+
+/// Paddle error envelope.
+// https://developer.paddle.com/api-reference/about/errors
+struct ApiError {
+    status: StatusCode,
+    code: &'static str,
+    detail: String,
+    errors: Vec<Value>,
+}
+
+impl ApiError {
+    fn new<T: Into<String>>(status: StatusCode, code: &'static str, detail: T) -> Self {
+        Self {
+            status,
+            code,
+            detail: detail.into(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn not_found(entity: &str) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            "not_found",
+            format!("Entity {} not found", entity),
+        )
+    }
+
+    fn invalid_field(field: &str, message: &str) -> Self {
+        Self {
+            errors: vec![json!({ "field": field, "message": message })],
+            ..Self::new(StatusCode::BAD_REQUEST, "invalid_field", "Invalid request.")
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let e_type = if self.status.is_server_error() {
+            "api_error"
+        } else {
+            "request_error"
+        };
+
+        let mut error = json!({
+            "type": e_type,
+            "code": self.code,
+            "detail": self.detail,
+            "documentation_url": format!("https://developer.paddle.com/errors/shared/{}", self.code),
+        });
+
+        if !self.errors.is_empty() {
+            error["errors"] = Value::Array(self.errors);
+        }
+
+        let body = json!({ "error": error, "meta": { "request_id": request_id() } });
+        (self.status, Json(body)).into_response()
+    }
+}
+
+// https://developer.paddle.com/api-reference/about/authentication
+fn authorize(headers: &HeaderMap) -> Result<(), ApiError> {
+    let Some(authorization) = headers.get("authorization") else {
+        return Err(ApiError::new(
+            StatusCode::UNAUTHORIZED,
+            "authentication_missing",
+            "Authentication header included, but incorrectly formatted",
+        ));
+    };
+
+    match authorization.to_str().map(|a| a.strip_prefix("Bearer ")) {
+        Ok(Some(key)) if !key.is_empty() => Ok(()),
+        _ => Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "authentication_malformed",
+            "Authentication header included, but incorrectly formatted",
+        )),
+    }
+}
+
+fn parse_body(body: &[u8]) -> Result<Map<String, Value>, ApiError> {
+    match serde_json::from_slice(body) {
+        Ok(Value::Object(body)) => Ok(body),
+        _ => Err(ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "invalid_json",
+            "Invalid JSON request body",
+        )),
+    }
+}
+
+// Response with a single entity
+fn entity(data: Value) -> Json<Value> {
+    Json(json!({ "data": data, "meta": { "request_id": request_id() } }))
+}
+
+// Comma separated query values
+fn query_list<'a>(query: &'a Params, key: &str) -> Option<Vec<&'a str>> {
+    query.get(key).map(|v| v.split(',').collect())
+}
+
+fn includes(query: &Params, include: &str) -> bool {
+    query_list(query, "include").is_some_and(|i| i.contains(&include))
+}
+
+// Filters shared by all list endpoints. Custom items are listed only on request.
+fn matches(entity: &Value, query: &Params) -> bool {
+    let field = |key: &str| entity[key].as_str().unwrap_or_default();
+    let filter = |key: &str, value: &str| query_list(query, key).is_none_or(|l| l.contains(&value));
+
+    filter("id", field("id"))
+        && filter("status", field("status"))
+        && match query.get("type") {
+            Some(p_type) => field("type") == p_type,
+            None => field("type") != "custom",
+        }
+}
+
+/// One page of the entities, ordered by `order_by` and starting after `after`.
+// https://developer.paddle.com/api-reference/about/pagination
+fn page(
+    mock: &Mock,
+    path: &str,
+    mut entities: Vec<Value>,
+    query: &Params,
+) -> Result<Json<Value>, ApiError> {
+    let per_page = match query.get("per_page").map(|p| p.parse::<usize>()) {
+        None => DEFAULT_PER_PAGE,
+        Some(Ok(per_page)) if (1..=MAX_PER_PAGE).contains(&per_page) => per_page,
+        Some(_) => {
+            return Err(ApiError::invalid_field(
+                "per_page",
+                "must be between 1 and 200",
+            ))
+        }
+    };
+
+    if let Some(order_by) = query.get("order_by") {
+        let (field, direction) = order_by
+            .strip_suffix(']')
+            .and_then(|o| o.split_once('['))
+            .ok_or_else(|| ApiError::invalid_field("order_by", "invalid format"))?;
+
+        entities.sort_by(|a, b| {
+            let ordering = a[field].to_string().cmp(&b[field].to_string());
+            match direction {
+                "DESC" => ordering.reverse(),
+                _ => ordering,
+            }
+        });
+    }
+
+    let estimated_total = entities.len();
+    let start = match query.get("after") {
+        Some(after) => entities
+            .iter()
+            .position(|e| e["id"] == after.as_str())
+            .map_or(estimated_total, |p| p + 1),
+        None => 0,
+    };
+    let data: Vec<Value> = entities.into_iter().skip(start).take(per_page).collect();
+    let has_more = start + data.len() < estimated_total;
+
+    // Same query with the last ID as cursor
+    let mut next = url::Url::parse(&mock.url)
+        .and_then(|url| url.join(path))
+        .map_err(|e| {
+            ApiError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "internal_error",
+                e.to_string(),
+            )
+        })?;
+    {
+        let mut pairs = next.query_pairs_mut();
+        for (key, value) in query.iter().filter(|(key, _)| *key != "after") {
+            pairs.append_pair(key, value);
+        }
+        if let Some(last) = data.last() {
+            pairs.append_pair("after", last["id"].as_str().unwrap_or_default());
+        }
+    }
+
+    Ok(Json(json!({
+        "data": data,
+        "meta": {
+            "request_id": request_id(),
+            "pagination": {
+                "per_page": per_page,
+                "next": next.as_str(),
+                "has_more": has_more,
+                "estimated_total": estimated_total
+            }
+        }
+    })))
+}
+
+fn request_id() -> String {
+    let hex = |n: usize| {
+        (0..n)
+            .map(|_| fastrand::alphanumeric().to_ascii_lowercase())
+            .collect::<String>()
+    };
+    format!("{}-{}-{}-{}-{}", hex(8), hex(4), hex(4), hex(4), hex(12))
+}
+
+/// RFC 3339 timestamp with microseconds, like Paddle's `created_at`.
+fn now() -> String {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs = since_epoch.as_secs() as i64;
+    let (days, rem) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}Z",
+        year,
+        month,
+        day,
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60,
+        since_epoch.subsec_micros()
+    )
+}
+
+// Copy the fields that can be updated, refusing values that aren't allowed
+fn update_fields(
+    entity: &mut Value,
+    body: Map<String, Value>,
+    fields: &[&str],
+) -> Result<(), ApiError> {
+    for (key, value) in body {
+        if !fields.contains(&key.as_str()) {
+            continue;
+        }
+
+        if key == "status" && !matches!(value.as_str(), Some("active" | "archived")) {
+            return Err(ApiError::invalid_field(
+                "status",
+                "must be active or archived",
+            ));
+        }
+
+        entity[key] = value;
+    }
+
+    entity["updated_at"] = Value::String(now());
+    Ok(())
+}
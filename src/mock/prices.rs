@@ -0,0 +1,186 @@
+//! Price endpoints.
+//!
+//! [Official document](https://developer.paddle.com/api-reference/prices/overview)
+
+use axum::body::Bytes;
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::Json;
+use serde_json::{json, Value};
+
+use super::{
+    authorize, entity, includes, matches, now, page, parse_body, query_list, update_fields,
+    ApiError, Params, Shared, Store,
+};
+
+const UPDATABLE: [&str; 11] = [
+    "description",
+    "name",
+    "type",
+    "billing_cycle",
+    "trial_period",
+    "tax_mode",
+    "unit_price",
+    "unit_price_overrides",
+    "quantity",
+    "custom_data",
+    "status",
+];
+
+// https://developer.paddle.com/api-reference/prices/list-prices
+pub(super) async fn list(
+    State(mock): State<Shared>,
+    Query(query): Query<Params>,
+    headers: HeaderMap,
+) -> Result<Json<Value>, ApiError> {
+    authorize(&headers)?;
+
+    let store = mock.store();
+    let product_ids = query_list(&query, "product_id");
+    let recurring = query.get("recurring").map(|r| r == "true");
+    let prices = store
+        .prices
+        .values()
+        .filter(|p| matches(p, &query))
+        .filter(|p| {
+            product_ids
+                .as_ref()
+                .is_none_or(|ids| ids.contains(&p["product_id"].as_str().unwrap_or_default()))
+        })
+        .filter(|p| recurring.is_none_or(|r| r == p["billing_cycle"].is_object()))
+        .map(|p| with_product(&store, p, includes(&query, "product")))
+        .collect();
+
+    page(&mock, "prices", prices, &query)
+}
+
+// https://developer.paddle.com/api-reference/prices/create-price
+pub(super) async fn create(
+    State(mock): State<Shared>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<Value>, ApiError> {
+    authorize(&headers)?;
+    let body = parse_body(&body)?;
+
+    if !body.get("description").is_some_and(Value::is_string) {
+        return Err(ApiError::invalid_field("description", "is required"));
+    }
+
+    validate_unit_price(body.get("unit_price"))?;
+
+    let mut store = mock.store();
+
+    match body.get("product_id").and_then(Value::as_str) {
+        Some(id) if store.products.contains_key(id) => (),
+        Some(_) => return Err(ApiError::invalid_field("product_id", "product not found")),
+        None => return Err(ApiError::invalid_field("product_id", "is required")),
+    }
+
+    let id = store.next_id("pri");
+    let created_at = now();
+
+    let mut price = json!({
+        "id": id,
+        "product_id": body["product_id"],
+        "description": null,
+        "type": "standard",
+        "name": null,
+        "billing_cycle": null,
+        "trial_period": null,
+        "tax_mode": "account_setting",
+        "unit_price": null,
+        "unit_price_overrides": [],
+        "quantity": { "minimum": 1, "maximum": 100 },
+        "status": "active",
+        "custom_data": null,
+        "import_meta": null,
+        "created_at": created_at,
+        "updated_at": created_at,
+    });
+    update_fields(&mut price, body, &UPDATABLE)?;
+    price["updated_at"] = Value::String(created_at);
+
+    store.prices.insert(id, price.clone());
+    Ok(entity(price))
+}
+
+// https://developer.paddle.com/api-reference/prices/get-price
+pub(super) async fn get(
+    State(mock): State<Shared>,
+    Path(id): Path<String>,
+    Query(query): Query<Params>,
+    headers: HeaderMap,
+) -> Result<Json<Value>, ApiError> {
+    authorize(&headers)?;
+
+    let store = mock.store();
+    let price = store.prices.get(&id).ok_or(ApiError::not_found(&id))?;
+
+    Ok(entity(with_product(
+        &store,
+        price,
+        includes(&query, "product"),
+    )))
+}
+
+// https://developer.paddle.com/api-reference/prices/update-price
+pub(super) async fn update(
+    State(mock): State<Shared>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<Value>, ApiError> {
+    authorize(&headers)?;
+    let body = parse_body(&body)?;
+
+    if body.contains_key("unit_price") {
+        validate_unit_price(body.get("unit_price"))?;
+    }
+
+    let mut store = mock.store();
+    let price = store.prices.get_mut(&id).ok_or(ApiError::not_found(&id))?;
+    update_fields(price, body, &UPDATABLE)?;
+
+    Ok(entity(price.clone()))
+}
+
+// Amount in the lowest denomination and a three letter currency code
+fn validate_unit_price(unit_price: Option<&Value>) -> Result<(), ApiError> {
+    let Some(unit_price) = unit_price else {
+        return Err(ApiError::invalid_field("unit_price", "is required"));
+    };
+
+    let amount = unit_price["amount"].as_str().unwrap_or_default();
+    if amount.is_empty() || !amount.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ApiError::invalid_field(
+            "unit_price.amount",
+            "must be a string of digits",
+        ));
+    }
+
+    let currency_code = unit_price["currency_code"].as_str().unwrap_or_default();
+    if currency_code.len() != 3 || !currency_code.bytes().all(|b| b.is_ascii_uppercase()) {
+        return Err(ApiError::invalid_field(
+            "unit_price.currency_code",
+            "must be an ISO 4217 currency code",
+        ));
+    }
+
+    Ok(())
+}
+
+fn with_product(store: &Store, price: &Value, include: bool) -> Value {
+    let mut price = price.clone();
+
+    if include {
+        let product_id = price["product_id"].as_str().unwrap_or_default();
+        price["product"] = store
+            .products
+            .get(product_id)
+            .cloned()
+            .unwrap_or(Value::Null);
+    }
+
+    price
+}
@@ -0,0 +1,165 @@
+//! Product endpoints.
+//!
+//! [Official document](https://developer.paddle.com/api-reference/products/overview)
+
+use axum::body::Bytes;
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::Json;
+use serde_json::{json, Value};
+
+use super::{
+    authorize, entity, includes, matches, now, page, parse_body, query_list, update_fields,
+    ApiError, Params, Shared, Store,
+};
+
+const TAX_CATEGORIES: [&str; 9] = [
+    "digital-goods",
+    "ebooks",
+    "implementation-services",
+    "professional-services",
+    "saas",
+    "software-programming-services",
+    "standard",
+    "training-services",
+    "website-hosting",
+];
+
+const UPDATABLE: [&str; 7] = [
+    "name",
+    "description",
+    "type",
+    "tax_category",
+    "image_url",
+    "custom_data",
+    "status",
+];
+
+// https://developer.paddle.com/api-reference/products/list-products
+pub(super) async fn list(
+    State(mock): State<Shared>,
+    Query(query): Query<Params>,
+    headers: HeaderMap,
+) -> Result<Json<Value>, ApiError> {
+    authorize(&headers)?;
+
+    let store = mock.store();
+    let tax_categories = query_list(&query, "tax_category");
+    let products = store
+        .products
+        .values()
+        .filter(|p| matches(p, &query))
+        .filter(|p| {
+            tax_categories
+                .as_ref()
+                .is_none_or(|t| t.contains(&p["tax_category"].as_str().unwrap_or_default()))
+        })
+        .map(|p| with_prices(&store, p, includes(&query, "prices")))
+        .collect();
+
+    page(&mock, "products", products, &query)
+}
+
+// https://developer.paddle.com/api-reference/products/create-product
+pub(super) async fn create(
+    State(mock): State<Shared>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<Value>, ApiError> {
+    authorize(&headers)?;
+    let body = parse_body(&body)?;
+
+    if !body.get("name").is_some_and(Value::is_string) {
+        return Err(ApiError::invalid_field("name", "is required"));
+    }
+
+    match body.get("tax_category").and_then(Value::as_str) {
+        Some(t) if TAX_CATEGORIES.contains(&t) => (),
+        Some(_) => return Err(ApiError::invalid_field("tax_category", "is invalid")),
+        None => return Err(ApiError::invalid_field("tax_category", "is required")),
+    }
+
+    let mut store = mock.store();
+    let id = store.next_id("pro");
+    let created_at = now();
+
+    let mut product = json!({
+        "id": id,
+        "name": null,
+        "description": null,
+        "type": "standard",
+        "tax_category": null,
+        "image_url": null,
+        "custom_data": null,
+        "status": "active",
+        "import_meta": null,
+        "created_at": created_at,
+        "updated_at": created_at,
+    });
+    update_fields(&mut product, body, &UPDATABLE)?;
+    product["updated_at"] = Value::String(created_at);
+
+    store.products.insert(id, product.clone());
+    Ok(entity(product))
+}
+
+// https://developer.paddle.com/api-reference/products/get-product
+pub(super) async fn get(
+    State(mock): State<Shared>,
+    Path(id): Path<String>,
+    Query(query): Query<Params>,
+    headers: HeaderMap,
+) -> Result<Json<Value>, ApiError> {
+    authorize(&headers)?;
+
+    let store = mock.store();
+    let product = store.products.get(&id).ok_or(ApiError::not_found(&id))?;
+
+    Ok(entity(with_prices(
+        &store,
+        product,
+        includes(&query, "prices"),
+    )))
+}
+
+// https://developer.paddle.com/api-reference/products/update-product
+pub(super) async fn update(
+    State(mock): State<Shared>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<Value>, ApiError> {
+    authorize(&headers)?;
+    let body = parse_body(&body)?;
+
+    if let Some(t) = body.get("tax_category").and_then(Value::as_str) {
+        if !TAX_CATEGORIES.contains(&t) {
+            return Err(ApiError::invalid_field("tax_category", "is invalid"));
+        }
+    }
+
+    let mut store = mock.store();
+    let product = store
+        .products
+        .get_mut(&id)
+        .ok_or(ApiError::not_found(&id))?;
+    update_fields(product, body, &UPDATABLE)?;
+
+    Ok(entity(product.clone()))
+}
+
+fn with_prices(store: &Store, product: &Value, include: bool) -> Value {
+    let mut product = product.clone();
+
+    if include {
+        let prices: Vec<Value> = store
+            .prices
+            .values()
+            .filter(|p| p["product_id"] == product["id"])
+            .cloned()
+            .collect();
+        product["prices"] = Value::Array(prices);
+    }
+
+    product
+}
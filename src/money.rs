@@ -0,0 +1,213 @@
+//! Amounts of money.
+//!
+//! Paddle sends amounts as strings in the lowest denomination of the currency,
+//! for example `"1050"` for 10.50 USD and `"1050"` for 1050 JPY.
+//! [`Money`] keeps that integer amount together with its [`CurrencyCode`].
+//!
+//! [Official document](https://developer.paddle.com/concepts/sell/supported-currencies)
+
+use std::fmt;
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::price::UnitPrice;
+use crate::Error;
+
+macro_rules! currency_codes {
+    ($( $code:ident = $minor_units:literal, )*) => {
+        /// ISO 4217 currency code.
+        #[allow(clippy::upper_case_acronyms)]
+        #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+        pub enum CurrencyCode {
+            $( $code, )*
+        }
+
+        impl CurrencyCode {
+            /// Three-letter code, for example `USD`.
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $( Self::$code => stringify!($code), )*
+                }
+            }
+
+            /// Number of digits after the decimal separator:
+            /// 0 for JPY, 2 for USD, 3 for KWD.
+            pub fn minor_units(&self) -> u32 {
+                match self {
+                    $( Self::$code => $minor_units, )*
+                }
+            }
+        }
+
+        impl FromStr for CurrencyCode {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $( stringify!($code) => Ok(Self::$code), )*
+                    _ => Err(Error::Validation(format!("unknown currency code: {}", s))),
+                }
+            }
+        }
+    };
+}
+
+// https://www.six-group.com/en/products-services/financial-information/data-standards.html
+currency_codes! {
+    AED = 2, AFN = 2, ALL = 2, AMD = 2, ANG = 2, AOA = 2, ARS = 2, AUD = 2, AWG = 2, AZN = 2,
+    BAM = 2, BBD = 2, BDT = 2, BGN = 2, BHD = 3, BIF = 0, BMD = 2, BND = 2, BOB = 2, BOV = 2,
+    BRL = 2, BSD = 2, BTN = 2, BWP = 2, BYN = 2, BZD = 2,
+    CAD = 2, CDF = 2, CHE = 2, CHF = 2, CHW = 2, CLF = 4, CLP = 0, CNY = 2, COP = 2, COU = 2,
+    CRC = 2, CUP = 2, CVE = 2, CZK = 2,
+    DJF = 0, DKK = 2, DOP = 2, DZD = 2,
+    EGP = 2, ERN = 2, ETB = 2, EUR = 2,
+    FJD = 2, FKP = 2,
+    GBP = 2, GEL = 2, GHS = 2, GIP = 2, GMD = 2, GNF = 0, GTQ = 2, GYD = 2,
+    HKD = 2, HNL = 2, HTG = 2, HUF = 2,
+    IDR = 2, ILS = 2, INR = 2, IQD = 3, IRR = 2, ISK = 0,
+    JMD = 2, JOD = 3, JPY = 0,
+    KES = 2, KGS = 2, KHR = 2, KMF = 0, KPW = 2, KRW = 0, KWD = 3, KYD = 2, KZT = 2,
+    LAK = 2, LBP = 2, LKR = 2, LRD = 2, LSL = 2, LYD = 3,
+    MAD = 2, MDL = 2, MGA = 2, MKD = 2, MMK = 2, MNT = 2, MOP = 2, MRU = 2, MUR = 2, MVR = 2,
+    MWK = 2, MXN = 2, MXV = 2, MYR = 2, MZN = 2,
+    NAD = 2, NGN = 2, NIO = 2, NOK = 2, NPR = 2, NZD = 2,
+    OMR = 3,
+    PAB = 2, PEN = 2, PGK = 2, PHP = 2, PKR = 2, PLN = 2, PYG = 0,
+    QAR = 2,
+    RON = 2, RSD = 2, RUB = 2, RWF = 0,
+    SAR = 2, SBD = 2, SCR = 2, SDG = 2, SEK = 2, SGD = 2, SHP = 2, SLE = 2, SOS = 2, SRD = 2,
+    SSP = 2, STN = 2, SVC = 2, SYP = 2, SZL = 2,
+    THB = 2, TJS = 2, TMT = 2, TND = 3, TOP = 2, TRY = 2, TTD = 2, TWD = 2, TZS = 2,
+    UAH = 2, UGX = 0, USD = 2, USN = 2, UYI = 0, UYU = 2, UYW = 4, UZS = 2,
+    VED = 2, VES = 2, VND = 0, VUV = 0,
+    WST = 2,
+    XAF = 0, XCD = 2, XCG = 2, XOF = 0, XPF = 0,
+    YER = 2,
+    ZAR = 2, ZMW = 2, ZWG = 2,
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Amount in the lowest denomination of its currency.
+///
+/// ```
+/// use paddle_api::money::{CurrencyCode, Money};
+/// use rust_decimal::Decimal;
+///
+/// let price = Money::from_major(Decimal::new(1050, 2), CurrencyCode::USD)?;
+/// assert_eq!(price.amount(), 1050);
+///
+/// let total = price.checked_mul(3).unwrap();
+/// assert_eq!(total.to_string(), "31.50 USD");
+/// # Ok::<(), paddle_api::Error>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct Money {
+    amount: i64,
+    currency: CurrencyCode,
+}
+
+impl Money {
+    /// `amount` is in the lowest denomination, e.g. cents for USD.
+    pub fn new(amount: i64, currency: CurrencyCode) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Convert an amount in the major unit, e.g. dollars for USD.
+    /// Fails when it has more decimal places than the currency.
+    pub fn from_major(amount: Decimal, currency: CurrencyCode) -> Result<Self, Error> {
+        let minor = amount
+            .checked_mul(Decimal::from(10_i64.pow(currency.minor_units())))
+            .filter(|minor| minor.fract().is_zero())
+            .and_then(|minor| i64::try_from(minor).ok())
+            .ok_or_else(|| {
+                Error::Validation(format!("{} can't be represented in {}", amount, currency))
+            })?;
+
+        Ok(Self::new(minor, currency))
+    }
+
+    pub fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    pub fn currency(&self) -> CurrencyCode {
+        self.currency
+    }
+
+    /// Amount in the major unit, with the decimal places of the currency.
+    pub fn to_major(&self) -> Decimal {
+        Decimal::new(self.amount, self.currency.minor_units())
+    }
+
+    /// `None` when the currencies differ or on overflow.
+    pub fn checked_add(&self, other: &Money) -> Option<Money> {
+        self.same_currency(other)?;
+        Some(Self::new(
+            self.amount.checked_add(other.amount)?,
+            self.currency,
+        ))
+    }
+
+    /// `None` when the currencies differ or on overflow.
+    pub fn checked_sub(&self, other: &Money) -> Option<Money> {
+        self.same_currency(other)?;
+        Some(Self::new(
+            self.amount.checked_sub(other.amount)?,
+            self.currency,
+        ))
+    }
+
+    /// Amount for `quantity` items, `None` on overflow.
+    pub fn checked_mul(&self, quantity: u32) -> Option<Money> {
+        Some(Self::new(
+            self.amount.checked_mul(i64::from(quantity))?,
+            self.currency,
+        ))
+    }
+
+    fn same_currency(&self, other: &Money) -> Option<()> {
+        (self.currency == other.currency).then_some(())
+    }
+}
+
+impl fmt::Display for Money {
+    /// Major unit and currency code, e.g. `10.50 USD`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.to_major(), self.currency)
+    }
+}
+
+impl TryFrom<&UnitPrice> for Money {
+    type Error = Error;
+
+    fn try_from(unit_price: &UnitPrice) -> Result<Self, Self::Error> {
+        let amount = unit_price.amount().parse().map_err(|e| {
+            Error::Validation(format!("invalid amount {}: {}", unit_price.amount(), e))
+        })?;
+
+        Ok(Self::new(amount, unit_price.currency_code().parse()?))
+    }
+}
+
+impl TryFrom<UnitPrice> for Money {
+    type Error = Error;
+
+    fn try_from(unit_price: UnitPrice) -> Result<Self, Self::Error> {
+        Self::try_from(&unit_price)
+    }
+}
+
+impl From<Money> for UnitPrice {
+    fn from(money: Money) -> Self {
+        UnitPrice::new(money.amount.to_string(), money.currency.code())
+    }
+}
@@ -0,0 +1,137 @@
+//! Auto-paginating streams for list endpoints.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::task::{Context, Poll};
+
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt, TryStreamExt};
+use url::Url;
+
+use crate::entities::{BaseListParamsGettersSetters, Meta};
+use crate::id::InvalidId;
+use crate::Error;
+
+// Entities of a page and `estimated_total`
+type Page<T> = (Vec<T>, Option<i32>);
+
+/// Stream of entities that requests the next page lazily,
+/// following `meta.pagination.next` until `has_more` is `false`.
+pub struct ListStream<'a, T> {
+    pages: BoxStream<'a, Result<Page<T>, Error>>,
+    buffer: VecDeque<T>,
+    estimated_total: Option<i32>,
+}
+
+impl<'a, T: Send + 'a> ListStream<'a, T> {
+    /// ### Arguments
+    /// `params` - list parameters of the first page\
+    /// `fetch` - request one page with the parameters
+    pub(crate) fn new<P, F, Fut>(params: P, fetch: F) -> Self
+    where
+        P: BaseListParamsGettersSetters + Clone + Send + 'a,
+        P::Id: FromStr<Err = InvalidId>,
+        F: Fn(P) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<(Vec<T>, Meta), Error>> + Send + 'a,
+    {
+        let pages = futures::stream::try_unfold(Some(params), move |params| {
+            let page = params.clone().map(&fetch);
+
+            async move {
+                let (Some(params), Some(page)) = (params, page) else {
+                    return Ok(None);
+                };
+
+                let (data, meta) = page.await?;
+                let pagination = meta.pagination.as_ref();
+                let next = match pagination.filter(|p| p.has_more) {
+                    Some(p) => next_after(&p.next)?.map(|after| params.set_after(after)),
+                    None => None,
+                };
+
+                Ok(Some(((data, pagination.map(|p| p.estimated_total)), next)))
+            }
+        });
+
+        Self {
+            pages: pages.boxed(),
+            buffer: VecDeque::new(),
+            estimated_total: None,
+        }
+    }
+}
+
+impl<T> ListStream<'_, T> {
+    /// Estimated number of entities, known after the first page.
+    pub fn estimated_total(&self) -> Option<i32> {
+        self.estimated_total
+    }
+
+    /// Collect the remaining entities.
+    ///
+    /// ### Arguments
+    /// `max_items` - stop after this many entities\
+    /// `progress` - called after every page with the number of collected
+    /// entities and `estimated_total`
+    pub async fn collect_all<F>(
+        mut self,
+        max_items: Option<usize>,
+        mut progress: F,
+    ) -> Result<Vec<T>, Error>
+    where
+        F: FnMut(usize, Option<i32>),
+    {
+        let max_items = max_items.unwrap_or(usize::MAX);
+        let mut items: Vec<T> = self.buffer.drain(..).take(max_items).collect();
+
+        while items.len() < max_items {
+            let Some((data, estimated_total)) = self.pages.try_next().await? else {
+                break;
+            };
+
+            self.estimated_total = estimated_total;
+            items.extend(data.into_iter().take(max_items - items.len()));
+            progress(items.len(), self.estimated_total);
+        }
+
+        Ok(items)
+    }
+}
+
+impl<T> Stream for ListStream<'_, T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Every field is `Unpin`
+        let this = self.get_mut();
+
+        loop {
+            if let Some(item) = this.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            match futures::ready!(this.pages.poll_next_unpin(cx)) {
+                Some(Ok((data, estimated_total))) => {
+                    this.estimated_total = estimated_total;
+                    this.buffer.extend(data);
+                }
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl<T> Unpin for ListStream<'_, T> {}
+
+// `after` of the `meta.pagination.next` URL, an ID of the listed entity type
+fn next_after<Id: FromStr<Err = InvalidId>>(next: &str) -> Result<Option<Id>, Error> {
+    let Ok(url) = Url::parse(next) else {
+        return Ok(None);
+    };
+
+    let after = url.query_pairs().find(|(key, _)| key == "after");
+    Ok(after.map(|(_, after)| after.parse()).transpose()?)
+}
@@ -0,0 +1,255 @@
+//! Consume Paddle events without exposing a webhook endpoint.
+//!
+//! [`EventPoller`] walks `/events` with the `after` cursor and keeps the
+//! cursor in a [`CheckpointStore`], so polling resumes where it stopped.
+//!
+//! ```no_run
+//! use futures::StreamExt;
+//! use paddle_api::poller::{EventPoller, FileCheckpoint};
+//! use paddle_api::{Client, Error};
+//!
+//! # async fn run(client: Client) -> Result<(), Error> {
+//! let mut events = Box::pin(EventPoller::new(&client, FileCheckpoint::new("events.cursor")).into_stream());
+//!
+//! while let Some(event) = events.next().await {
+//!     println!("{}", event?.event_type());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::Stream;
+
+use crate::entities::event::list::ListEventsParams;
+use crate::id::EventId;
+use crate::util::write_atomic;
+use crate::webhook::event::WebhookEvent;
+use crate::{Client, Error};
+
+/// Storage of the ID of the last consumed event.
+///
+/// [`EventPoller`] calls it on the blocking thread pool, so the methods may block.
+pub trait CheckpointStore: Send + Sync + 'static {
+    /// ID of the last consumed event, `None` to start from the oldest event.
+    fn load(&self) -> std::io::Result<Option<EventId>>;
+    /// Remember the ID of the last consumed event.
+    fn save(&self, event_id: &EventId) -> std::io::Result<()>;
+}
+
+/// Checkpoint kept in memory, lost on restart.
+#[derive(Default)]
+pub struct MemoryCheckpoint {
+    event_id: Mutex<Option<EventId>>,
+}
+
+/// Checkpoint kept in a file that contains the event ID.
+pub struct FileCheckpoint {
+    path: PathBuf,
+}
+
+/// Polls Paddle events.
+pub struct EventPoller<'a, C> {
+    client: &'a Client,
+    store: Arc<C>,
+    per_page: Option<i32>,
+    event_type: Option<Vec<String>>,
+    min_interval: Duration,
+    max_interval: Duration,
+}
+
+struct PollState<'a, C> {
+    poller: EventPoller<'a, C>,
+    cursor: Option<EventId>,
+    loaded: bool,
+    buffer: VecDeque<WebhookEvent>,
+    // ID of the last yielded event, saved when the next one is requested
+    pending: Option<EventId>,
+    caught_up: bool,
+    interval: Duration,
+}
+
+impl MemoryCheckpoint {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start after the event with this ID.
+    pub fn starting_after(event_id: EventId) -> Self {
+        Self {
+            event_id: Mutex::new(Some(event_id)),
+        }
+    }
+}
+
+impl CheckpointStore for MemoryCheckpoint {
+    fn load(&self) -> std::io::Result<Option<EventId>> {
+        Ok(self
+            .event_id
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone())
+    }
+
+    fn save(&self, event_id: &EventId) -> std::io::Result<()> {
+        *self.event_id.lock().unwrap_or_else(|e| e.into_inner()) = Some(event_id.clone());
+        Ok(())
+    }
+}
+
+impl FileCheckpoint {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl CheckpointStore for FileCheckpoint {
+    fn load(&self) -> std::io::Result<Option<EventId>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(s) if s.trim().is_empty() => Ok(None),
+            Ok(s) => s
+                .trim()
+                .parse()
+                .map(Some)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn save(&self, event_id: &EventId) -> std::io::Result<()> {
+        write_atomic(&self.path, event_id.as_str())
+    }
+}
+
+impl<'a, C: CheckpointStore> EventPoller<'a, C> {
+    pub fn new(client: &'a Client, store: C) -> Self {
+        Self {
+            client,
+            store: Arc::new(store),
+            per_page: None,
+            event_type: None,
+            min_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(60),
+        }
+    }
+
+    /// Set how many events are requested per page.
+    /// `Default: 50; Maximum: 200`.
+    pub fn set_per_page(mut self, per_page: i32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Only poll events of these types, for example `subscription.updated`.
+    pub fn set_event_type<T, I>(mut self, event_type: T) -> Self
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<String>,
+    {
+        self.event_type = Some(event_type.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the wait between polls once all events are consumed.
+    /// The wait doubles from `min` up to `max` while no new events arrive.
+    /// `Default: 1 second to 60 seconds`.
+    pub fn set_poll_interval(mut self, min: Duration, max: Duration) -> Self {
+        self.min_interval = min;
+        self.max_interval = max.max(min);
+        self
+    }
+
+    /// Stream of events, oldest first. It never ends.
+    ///
+    /// The ID of a yielded event is saved to the checkpoint store when
+    /// the next event is requested, so an event that was being processed
+    /// when the program stopped is delivered again.
+    pub fn into_stream(self) -> impl Stream<Item = Result<WebhookEvent, Error>> + 'a
+    where
+        C: 'a,
+    {
+        let interval = self.min_interval;
+
+        futures::stream::unfold(
+            PollState {
+                poller: self,
+                cursor: None,
+                loaded: false,
+                buffer: VecDeque::new(),
+                pending: None,
+                caught_up: false,
+                interval,
+            },
+            |mut state| async move {
+                let next = state.next().await;
+                Some((next, state))
+            },
+        )
+    }
+}
+
+impl<C: CheckpointStore> PollState<'_, C> {
+    async fn next(&mut self) -> Result<WebhookEvent, Error> {
+        if let Some(event_id) = self.pending.take() {
+            self.with_store(move |store| store.save(&event_id)).await?;
+        }
+
+        if !self.loaded {
+            self.cursor = self.with_store(|store| store.load()).await?;
+            self.loaded = true;
+        }
+
+        loop {
+            if let Some(event) = self.buffer.pop_front() {
+                self.cursor = Some(event.event_id().clone());
+                self.pending = Some(event.event_id().clone());
+                return Ok(event);
+            }
+
+            if self.caught_up {
+                tokio::time::sleep(self.interval).await;
+                self.interval = (self.interval * 2).min(self.poller.max_interval);
+            }
+
+            // Retry after the backoff if the request fails
+            self.caught_up = true;
+
+            let mut params = ListEventsParams::default();
+            if let Some(cursor) = &self.cursor {
+                params = params.set_after(cursor.clone());
+            }
+            if let Some(per_page) = self.poller.per_page {
+                params = params.set_per_page(per_page);
+            }
+            if let Some(event_type) = &self.poller.event_type {
+                params = params.set_event_type(event_type);
+            }
+
+            let (data, meta) = self.poller.client.get_list_events(params).await?.into();
+
+            self.caught_up = !meta.pagination.is_some_and(|p| p.has_more);
+            if !data.is_empty() {
+                self.interval = self.poller.min_interval;
+            }
+            self.buffer.extend(data);
+        }
+    }
+
+    // Off the runtime, file stores write and sync to disk
+    async fn with_store<T, F>(&self, f: F) -> Result<T, Error>
+    where
+        T: Send + 'static,
+        F: FnOnce(&C) -> std::io::Result<T> + Send + 'static,
+    {
+        let store = self.poller.store.clone();
+        let r = tokio::task::spawn_blocking(move || f(&store))
+            .await
+            .map_err(std::io::Error::other)?;
+        Ok(r?)
+    }
+}
@@ -0,0 +1,140 @@
+//! Client-side rate limiting.
+//!
+//! Paddle limits the number of requests per IP address and per account,
+//! and responds with `429 Too Many Requests` once the limit is exceeded.
+//! A [`RateLimiter`] spreads the requests of all clones of a
+//! [`Client`](crate::Client) to stay under the quota.
+//!
+//! [Official document](https://developer.paddle.com/api-reference/about/rate-limiting)
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// Request quota: `requests` per `period`, with up to `burst` requests at once.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct RateLimit {
+    requests: u32,
+    period: Duration,
+    burst: u32,
+}
+
+impl Default for RateLimit {
+    /// Paddle's limit of 240 requests per minute per IP address.
+    fn default() -> Self {
+        Self::new(240, Duration::from_secs(60))
+    }
+}
+
+impl RateLimit {
+    /// Allow `requests` per `period`. The burst is the whole quota.
+    pub fn new(requests: u32, period: Duration) -> Self {
+        let requests = requests.max(1);
+        Self {
+            requests,
+            period,
+            burst: requests,
+        }
+    }
+
+    pub fn requests(&self) -> u32 {
+        self.requests
+    }
+
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Set how many requests may be sent at once after a quiet period.
+    pub fn set_burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    // Tokens added per second
+    fn rate(&self) -> f64 {
+        self.requests as f64 / self.period.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+/// Token bucket shared by all clones of a client.
+pub struct RateLimiter {
+    limit: RateLimit,
+    bucket: Mutex<Bucket>,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter with a full bucket.
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            bucket: Mutex::new(Bucket {
+                tokens: limit.burst as f64,
+                updated: Instant::now(),
+            }),
+        }
+    }
+
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    /// Number of requests that can be sent right now.
+    pub fn available(&self) -> u32 {
+        let mut bucket = self.lock();
+        self.refill(&mut bucket);
+        bucket.tokens as u32
+    }
+
+    /// Wait until a request may be sent and take its token.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.lock();
+                self.refill(&mut bucket);
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.limit.rate())
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+
+        bucket.tokens = (bucket.tokens + elapsed * self.limit.rate()).min(self.limit.burst as f64);
+        bucket.updated = now;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Bucket> {
+        self.bucket.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(any(feature = "debug", feature = "logs", test))]
+impl std::fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("limit", &self.limit)
+            .field("available", &self.available())
+            .finish()
+    }
+}
@@ -0,0 +1,200 @@
+//! Automatic retries of failed requests.
+
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+
+use crate::Error;
+
+// Also what `Error::is_retryable` considers transient
+pub(crate) const DEFAULT_RETRYABLE_STATUSES: [StatusCode; 5] = [
+    StatusCode::TOO_MANY_REQUESTS,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    StatusCode::BAD_GATEWAY,
+    StatusCode::SERVICE_UNAVAILABLE,
+    StatusCode::GATEWAY_TIMEOUT,
+];
+
+/// When and how often the [`Client`](crate::Client) retries failed requests.
+///
+/// Requests are retried on the configured status codes, timeouts and
+/// connection errors. The wait doubles from `base_delay` up to `max_delay`,
+/// unless the response has a `Retry-After` header, which is honored up to
+/// `max_retry_after`.
+///
+/// `POST` and `PATCH` requests aren't idempotent, so they are retried only
+/// with [`set_retry_non_idempotent`](RetryPolicy::set_retry_non_idempotent).
+#[derive(Clone)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    max_retry_after: Duration,
+    jitter: bool,
+    retryable_statuses: Vec<StatusCode>,
+    retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    /// 3 attempts, 500 ms to 30 s with jitter, on `429`, `500`, `502`, `503` and `504`.
+    /// `Retry-After` is honored up to 60 s, the block after a Paddle rate limit hit.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_retry_after: Duration::from_secs(60),
+            jitter: true,
+            retryable_statuses: DEFAULT_RETRYABLE_STATUSES.to_vec(),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Policy that never retries.
+    pub fn none() -> Self {
+        Self::default().set_max_attempts(1)
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Set the total number of attempts, including the first one.
+    pub fn set_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn base_delay(&self) -> Duration {
+        self.base_delay
+    }
+
+    /// Set the wait before the first retry.
+    pub fn set_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// Set the longest computed wait between attempts.
+    pub fn set_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn max_retry_after(&self) -> Duration {
+        self.max_retry_after
+    }
+
+    /// Set the longest `Retry-After` that is waited for.
+    /// A longer one stops retrying.
+    pub fn set_max_retry_after(mut self, max_retry_after: Duration) -> Self {
+        self.max_retry_after = max_retry_after;
+        self
+    }
+
+    pub fn jitter(&self) -> bool {
+        self.jitter
+    }
+
+    /// Randomize the wait between half and all of the computed delay,
+    /// so concurrent clients don't retry at the same time.
+    pub fn set_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn retryable_statuses(&self) -> &[StatusCode] {
+        &self.retryable_statuses
+    }
+
+    /// Set the response status codes that are retried.
+    pub fn set_retryable_statuses<T, I>(mut self, statuses: T) -> Self
+    where
+        T: IntoIterator<Item = I>,
+        I: Into<StatusCode>,
+    {
+        self.retryable_statuses = statuses.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn retry_non_idempotent(&self) -> bool {
+        self.retry_non_idempotent
+    }
+
+    /// Also retry `POST` and `PATCH` requests.
+    /// They may be applied twice if the first response was lost.
+    pub fn set_retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Wait before the next attempt, `None` if the request shouldn't be retried.
+    ///
+    /// `attempt` is the number of the failed attempt, starting at 1.
+    pub(crate) fn next_delay(
+        &self,
+        method: &Method,
+        attempt: u32,
+        error: &Error,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !(method.is_idempotent() || self.retry_non_idempotent) {
+            return None;
+        }
+
+        let retryable = match error {
+            Error::Api { status, .. } | Error::UnexpectedResponse { status, .. } => {
+                self.retryable_statuses.contains(status)
+            }
+            Error::Http(e) => e.is_timeout() || e.is_connect(),
+            _ => false,
+        };
+
+        if !retryable {
+            return None;
+        }
+
+        if let Some(retry_after) = retry_after {
+            return (retry_after <= self.max_retry_after).then_some(retry_after);
+        }
+
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(self.max_delay);
+
+        if self.jitter {
+            let half = delay / 2;
+            Some(half + half.mul_f64(fastrand::f64()))
+        } else {
+            Some(delay)
+        }
+    }
+}
+
+/// `Retry-After` as seconds or an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    httpdate::parse_http_date(value)
+        .ok()?
+        .duration_since(SystemTime::now())
+        .ok()
+        .or(Some(Duration::ZERO))
+}
@@ -0,0 +1,59 @@
+//! Secret values that never appear in logs.
+
+use std::fmt;
+
+use zeroize::Zeroize;
+
+/// Secret string, e.g. the API key.
+///
+/// `Debug` and `Display` print `[REDACTED]`,
+/// and the memory is zeroed when the value is dropped.
+#[derive(Clone)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new<T: Into<String>>(secret: T) -> Self {
+        Self(secret.into())
+    }
+
+    /// The secret value. Don't log it.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+impl From<&String> for Secret {
+    fn from(secret: &String) -> Self {
+        Self(secret.clone())
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret([REDACTED])")
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
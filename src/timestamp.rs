@@ -0,0 +1,286 @@
+//! RFC 3339 timestamps.
+//!
+//! Paddle sends every date as a UTC RFC 3339 string with microseconds,
+//! for example `2023-08-18T10:46:18.792661Z`. [`Timestamp`] keeps that string
+//! as sent together with the instant it denotes, and converts it to a `chrono`
+//! or `time` datetime with the `chrono` and `time` features.
+//!
+//! [Official document](https://developer.paddle.com/api-reference/about/data-types#datetime)
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// Timestamp sent by Paddle, e.g. `created_at`, `updated_at` or `occurred_at`.
+///
+/// Timestamps compare chronologically, so `2023-08-18T10:46:18Z` is equal to
+/// `2023-08-18T10:46:18.000Z` and earlier than `2023-08-18T10:46:18.1Z`.
+/// Serializing writes the string as it was parsed.
+///
+/// Conversions from a datetime truncate it to microseconds, the precision of Paddle,
+/// so a timestamp converted to a datetime and back is unchanged.
+///
+/// ```
+/// use paddle_api::timestamp::Timestamp;
+///
+/// let earlier: Timestamp = "2023-08-18T10:46:18Z".parse()?;
+/// let later: Timestamp = "2023-08-18T10:46:18.1Z".parse()?;
+/// assert!(earlier < later);
+/// assert!("2023-08-18".parse::<Timestamp>().is_err());
+/// # Ok::<(), paddle_api::Error>(())
+/// ```
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+#[serde(try_from = "String", into = "String")]
+pub struct Timestamp {
+    raw: String,
+    seconds: i64,
+    nanos: u32,
+}
+
+impl Timestamp {
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Seconds since the Unix epoch.
+    pub fn unix_timestamp(&self) -> i64 {
+        self.seconds
+    }
+
+    /// Nanoseconds past [`unix_timestamp`](Self::unix_timestamp).
+    pub fn subsec_nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Convert to a `chrono` UTC datetime.
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(&self) -> chrono::DateTime<chrono::Utc> {
+        // `parse` rejects instants outside years 0000 to 9999 UTC
+        chrono::DateTime::from_timestamp(self.seconds, self.nanos).unwrap()
+    }
+
+    /// Convert to a `time` UTC datetime.
+    #[cfg(feature = "time")]
+    pub fn to_time(&self) -> time::OffsetDateTime {
+        let nanos = i128::from(self.seconds) * 1_000_000_000 + i128::from(self.nanos);
+
+        // `parse` rejects instants outside years 0000 to 9999 UTC
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap()
+    }
+}
+
+// `date-time` of RFC 3339: `2023-08-18T10:46:18.792661Z` or `2023-08-18T12:46:18+02:00`
+fn parse(ts: &str) -> Option<(i64, u32)> {
+    let b = ts.as_bytes();
+    let digits = |range: std::ops::Range<usize>| -> Option<u32> {
+        let part = b.get(range)?;
+        part.iter().all(u8::is_ascii_digit).then(|| {
+            part.iter()
+                .fold(0, |n, digit| n * 10 + u32::from(digit - b'0'))
+        })
+    };
+
+    let (year, month, day) = (digits(0..4)?, digits(5..7)?, digits(8..10)?);
+    let (hour, minute, second) = (digits(11..13)?, digits(14..16)?, digits(17..19)?);
+
+    if b.get(4) != Some(&b'-')
+        || b.get(7) != Some(&b'-')
+        || !matches!(b.get(10), Some(b'T' | b't'))
+        || b.get(13) != Some(&b':')
+        || b.get(16) != Some(&b':')
+        || !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+
+    // Fraction of any length, digits past nanoseconds are dropped
+    let mut rest = &ts[19..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 {
+            return None;
+        }
+        nanos = format!("{:0<9.9}", &fraction[..len]).parse().ok()?;
+        rest = &fraction[len..];
+    }
+
+    let offset = match rest.as_bytes() {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let end = b.len();
+            let (hours, minutes) = (digits(end - 5..end - 3)?, digits(end - 2..end)?);
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+
+            let offset = i64::from(hours * 3600 + minutes * 60);
+            if *sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => return None,
+    };
+
+    let days = days_from_civil(i64::from(year), month, day);
+    let seconds = days * 86400 + i64::from(hour * 3600 + minute * 60 + second) - offset;
+
+    // The offset may move the instant out of years 0000 to 9999 UTC,
+    // e.g. `9999-12-31T23:59:59-01:00`, which `chrono` and `time` can't represent
+    let min = days_from_civil(0, 1, 1) * 86400;
+    let max = days_from_civil(10000, 1, 1) * 86400 - 1;
+    (min..=max).contains(&seconds).then_some((seconds, nanos))
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01 of a proleptic Gregorian date
+// https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * i64::from((month + 9) % 12) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+impl FromStr for Timestamp {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.to_string())
+    }
+}
+
+impl TryFrom<String> for Timestamp {
+    type Error = Error;
+
+    fn try_from(raw: String) -> Result<Self, Self::Error> {
+        match parse(&raw) {
+            Some((seconds, nanos)) => Ok(Self {
+                raw,
+                seconds,
+                nanos,
+            }),
+            None => Err(Error::Validation(format!(
+                "invalid RFC 3339 timestamp: {}",
+                raw
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp {
+    fn from(datetime: chrono::DateTime<Tz>) -> Self {
+        let datetime = datetime.to_utc();
+
+        Self {
+            raw: datetime.to_rfc3339_opts(chrono::SecondsFormat::Micros, true),
+            seconds: datetime.timestamp(),
+            nanos: datetime.timestamp_subsec_micros() * 1000,
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Timestamp {
+    fn from(datetime: time::OffsetDateTime) -> Self {
+        let datetime = datetime.to_offset(time::UtcOffset::UTC);
+
+        Self {
+            raw: format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}Z",
+                datetime.year(),
+                u8::from(datetime.month()),
+                datetime.day(),
+                datetime.hour(),
+                datetime.minute(),
+                datetime.second(),
+                datetime.microsecond()
+            ),
+            seconds: datetime.unix_timestamp(),
+            nanos: datetime.microsecond() * 1000,
+        }
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        (self.seconds, self.nanos) == (other.seconds, other.nanos)
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.seconds, self.nanos).hash(state);
+    }
+}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.seconds, self.nanos).cmp(&(other.seconds, other.nanos))
+    }
+}
+
+impl From<Timestamp> for String {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.raw
+    }
+}
+
+impl AsRef<str> for Timestamp {
+    fn as_ref(&self) -> &str {
+        &self.raw
+    }
+}
+
+/// Compares the string as sent, not the instant.
+impl PartialEq<str> for Timestamp {
+    fn eq(&self, other: &str) -> bool {
+        self.raw == other
+    }
+}
+
+impl PartialEq<&str> for Timestamp {
+    fn eq(&self, other: &&str) -> bool {
+        self.raw == *other
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
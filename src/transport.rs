@@ -0,0 +1,81 @@
+//! HTTP transport.
+//!
+//! [`Client`](crate::Client) sends every attempt through a [`Transport`].
+//! [`ReqwestTransport`] is the default, any other implementation can answer
+//! requests without a network, e.g. with canned JSON in tests.
+
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use reqwest::{Client as RClient, Request, Response, StatusCode};
+
+use crate::util::Shared;
+use crate::Error;
+
+/// Sends a request and returns its response.
+///
+/// Any status is a response, `Err` is for requests that got none.
+///
+/// ```
+/// use paddle_api::transport::{self, Transport};
+/// use paddle_api::Error;
+/// use futures::future::BoxFuture;
+/// use reqwest::{Request, Response, StatusCode};
+///
+/// struct Canned;
+///
+/// impl Transport for Canned {
+///     fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+///         let response = match request.url().path() {
+///             "/products" => transport::response(
+///                 StatusCode::OK,
+///                 r#"{"data":[],"meta":{"request_id":"id"}}"#,
+///             ),
+///             _ => transport::response(StatusCode::NOT_FOUND, ""),
+///         };
+///         Box::pin(async move { Ok(response) })
+///     }
+/// }
+/// ```
+pub trait Transport: Send + Sync {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>>;
+}
+
+/// Transport of a `reqwest` client.
+#[derive(Clone, Default)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct ReqwestTransport {
+    client: RClient,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: RClient) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        Box::pin(async move { Ok(self.client.execute(request).await?) })
+    }
+}
+
+/// JSON response with the status and body, for transports without a network.
+pub fn response<T: Into<String>>(status: StatusCode, body: T) -> Response {
+    let mut response = http::Response::new(body.into());
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    response.into()
+}
+
+pub(crate) type SharedTransport = Shared<dyn Transport>;
+
+impl SharedTransport {
+    pub(crate) fn new<T: Transport + 'static>(transport: T) -> Self {
+        Shared(Arc::new(transport))
+    }
+}
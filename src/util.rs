@@ -0,0 +1,59 @@
+//! Helpers used across the modules of the crate.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Trait object shared by the clones of a client,
+/// e.g. `Shared<dyn Transport>`.
+pub(crate) struct Shared<T: ?Sized>(pub(crate) Arc<T>);
+
+impl<T: ?Sized> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ?Sized> Deref for Shared<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+// Only the name of the trait, implementations may not be `Debug`
+#[cfg(any(feature = "debug", feature = "logs", test))]
+impl<T: ?Sized> std::fmt::Debug for Shared<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = std::any::type_name::<T>();
+        f.write_str(name.rsplit("::").next().unwrap_or(name))
+    }
+}
+
+/// Replace the file at `path` with `contents`.
+///
+/// Writes and syncs `<path>.tmp` first, then renames it over `path`,
+/// so a crash never leaves a partially written file. Blocks on the disk.
+pub(crate) fn write_atomic<C: AsRef<[u8]>>(path: &Path, contents: C) -> io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+
+    let mut file = File::create(&tmp)?;
+    file.write_all(contents.as_ref())?;
+    file.sync_all()?;
+    drop(file);
+
+    std::fs::rename(&tmp, path)?;
+
+    // Persist the rename itself, directories can't be opened on Windows
+    #[cfg(unix)]
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        File::open(dir)?.sync_all()?;
+    }
+
+    Ok(())
+}
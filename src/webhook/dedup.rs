@@ -0,0 +1,216 @@
+//! Deduplication of webhook events.
+//!
+//! Paddle may deliver the same notification more than once,
+//! remember processed events to handle each of them only once.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+use super::event::WebhookEvent;
+use crate::id::NotificationId;
+
+/// Storage of processed event keys.
+///
+/// A key is claimed while its event is processed, so concurrent deliveries
+/// of the same event are handled once. The methods may block,
+/// [`WebhookReceiver`](super::server::WebhookReceiver) calls `mark_processed`
+/// on the blocking thread pool.
+pub trait EventDeduplicator: Send + Sync {
+    /// Whether the event with this key was already processed.
+    fn is_processed(&self, key: &str) -> std::io::Result<bool>;
+    /// Claim the key for processing, `false` when its event was already
+    /// processed or is being processed.
+    /// Check and claim must be a single atomic step.
+    fn try_claim(&self, key: &str) -> std::io::Result<bool>;
+    /// Give up the claim of the key, e.g. after the handler failed.
+    fn release(&self, key: &str) -> std::io::Result<()>;
+    /// Remember the event with this key as processed, releasing its claim.
+    fn mark_processed(&self, key: &str) -> std::io::Result<()>;
+}
+
+/// Which ID of the event is used as deduplication key.
+#[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub enum DedupKey {
+    /// `event_id`, the same for every notification about the event.
+    #[default]
+    EventId,
+    /// `notification_id`, unique per notification destination.
+    /// Falls back to `event_id` when the event has no notification ID.
+    NotificationId,
+}
+
+impl DedupKey {
+    /// Key of the event.
+    pub fn key<'a>(&self, event: &'a WebhookEvent) -> &'a str {
+        match self {
+            Self::EventId => event.event_id().as_str(),
+            Self::NotificationId => event
+                .notification_id()
+                .map_or(event.event_id().as_str(), NotificationId::as_str),
+        }
+    }
+}
+
+/// In-memory deduplicator that keeps the `capacity` most recently used keys.
+pub struct MemoryDeduplicator {
+    state: Mutex<(Lru, HashSet<String>)>,
+}
+
+struct Lru {
+    capacity: usize,
+    tick: u64,
+    keys: HashMap<String, u64>,
+    order: BTreeMap<u64, String>,
+}
+
+impl MemoryDeduplicator {
+    pub fn new(capacity: usize) -> Self {
+        let lru = Lru {
+            capacity,
+            tick: 0,
+            keys: HashMap::new(),
+            order: BTreeMap::new(),
+        };
+
+        Self {
+            state: Mutex::new((lru, HashSet::new())),
+        }
+    }
+}
+
+impl Lru {
+    // Move the key to the most recently used position
+    fn touch(&mut self, key: &str) -> bool {
+        let Some(tick) = self.keys.get_mut(key) else {
+            return false;
+        };
+
+        self.tick += 1;
+        if let Some(key) = self.order.remove(tick) {
+            self.order.insert(self.tick, key);
+        }
+        *tick = self.tick;
+        true
+    }
+
+    fn insert(&mut self, key: &str) {
+        if self.capacity == 0 || self.touch(key) {
+            return;
+        }
+
+        self.tick += 1;
+        self.keys.insert(key.to_string(), self.tick);
+        self.order.insert(self.tick, key.to_string());
+
+        while self.keys.len() > self.capacity {
+            if let Some((_, oldest)) = self.order.pop_first() {
+                self.keys.remove(&oldest);
+            }
+        }
+    }
+}
+
+impl MemoryDeduplicator {
+    fn lock(&self) -> MutexGuard<'_, (Lru, HashSet<String>)> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl EventDeduplicator for MemoryDeduplicator {
+    fn is_processed(&self, key: &str) -> std::io::Result<bool> {
+        Ok(self.lock().0.touch(key))
+    }
+
+    fn try_claim(&self, key: &str) -> std::io::Result<bool> {
+        let (lru, claimed) = &mut *self.lock();
+        Ok(!lru.touch(key) && claimed.insert(key.to_string()))
+    }
+
+    fn release(&self, key: &str) -> std::io::Result<()> {
+        self.lock().1.remove(key);
+        Ok(())
+    }
+
+    fn mark_processed(&self, key: &str) -> std::io::Result<()> {
+        let (lru, claimed) = &mut *self.lock();
+        lru.insert(key);
+        claimed.remove(key);
+        Ok(())
+    }
+}
+
+/// Deduplicator that appends processed keys to a file, one per line,
+/// so they survive restarts.
+///
+/// All keys are kept in memory and the file is never truncated.
+pub struct FileDeduplicator {
+    state: Mutex<FileState>,
+}
+
+struct FileState {
+    file: File,
+    processed: HashSet<String>,
+    claimed: HashSet<String>,
+}
+
+impl FileDeduplicator {
+    /// Open the file, creating it if it doesn't exist, and load the processed keys.
+    pub fn open<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)?;
+
+        let processed = BufReader::new(&file)
+            .lines()
+            .filter(|line| !matches!(line, Ok(line) if line.is_empty()))
+            .collect::<std::io::Result<_>>()?;
+
+        Ok(Self {
+            state: Mutex::new(FileState {
+                file,
+                processed,
+                claimed: HashSet::new(),
+            }),
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, FileState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl EventDeduplicator for FileDeduplicator {
+    fn is_processed(&self, key: &str) -> std::io::Result<bool> {
+        Ok(self.lock().processed.contains(key))
+    }
+
+    fn try_claim(&self, key: &str) -> std::io::Result<bool> {
+        let state = &mut *self.lock();
+        Ok(!state.processed.contains(key) && state.claimed.insert(key.to_string()))
+    }
+
+    fn release(&self, key: &str) -> std::io::Result<()> {
+        self.lock().claimed.remove(key);
+        Ok(())
+    }
+
+    /// Blocks until the key is synced to the file.
+    fn mark_processed(&self, key: &str) -> std::io::Result<()> {
+        let state = &mut *self.lock();
+
+        if !state.processed.contains(key) {
+            writeln!(state.file, "{}", key)?;
+            state.file.sync_data()?;
+            state.processed.insert(key.to_string());
+        }
+
+        state.claimed.remove(key);
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use crate::entities::price::Price;
+use crate::entities::product::Product;
+use crate::entities::EntityBaseGettersSetters;
+use crate::id::{EventId, NotificationId};
+use crate::timestamp::Timestamp;
+use crate::webhook::WebhookError;
+
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+/// Notification envelope sent by Paddle.
+///
+/// ```json
+/// {
+///   "event_id": "evt_01h...",
+///   "event_type": "product.created",
+///   "occurred_at": "2023-08-21T11:57:47.390028Z",
+///   "notification_id": "ntf_01h...",
+///   "data": { ... }
+/// }
+/// ```
+// https://developer.paddle.com/webhooks/overview
+#[derive(Deserialize)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub struct WebhookEvent {
+    event_id: EventId,
+    notification_id: Option<NotificationId>,
+    occurred_at: Timestamp,
+    #[serde(flatten)]
+    data: EventData,
+}
+
+// `event_type` and `data` before dispatching on the type
+#[derive(Deserialize)]
+struct RawEventData {
+    event_type: String,
+    data: Value,
+}
+
+macro_rules! event_data {
+    ($( $(#[$doc:meta])* $variant:ident($ty:ty) = $name:literal, )*) => {
+        /// Payload of a webhook event, selected by `event_type`.
+        ///
+        /// Event types unknown to this crate are kept in [`EventData::Other`].
+        // https://developer.paddle.com/webhooks/overview#events
+        #[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+        pub enum EventData {
+            $( $(#[$doc])* $variant($ty), )*
+            /// Event type unknown to this crate
+            Other { event_type: String, data: Value },
+        }
+
+        impl EventData {
+            /// Type of the event, for example `product.created`.
+            pub fn event_type(&self) -> &str {
+                match self {
+                    $( Self::$variant(_) => $name, )*
+                    Self::Other { event_type, .. } => event_type,
+                }
+            }
+
+            /// ID of the entity in the payload.
+            pub fn entity_id(&self) -> Option<&str> {
+                match self {
+                    $( Self::$variant(entity) => EventEntity::entity_id(entity), )*
+                    Self::Other { data, .. } => data.entity_id(),
+                }
+            }
+
+            /// `updated_at` of the entity in the payload.
+            pub fn entity_updated_at(&self) -> Option<Timestamp> {
+                match self {
+                    $( Self::$variant(entity) => EventEntity::updated_at(entity), )*
+                    Self::Other { data, .. } => data.updated_at(),
+                }
+            }
+
+            fn from_raw(raw: RawEventData) -> Result<Self, serde_json::Error> {
+                Ok(match raw.event_type.as_str() {
+                    $( $name => Self::$variant(serde_json::from_value(raw.data)?), )*
+                    _ => Self::Other {
+                        event_type: raw.event_type,
+                        data: raw.data,
+                    },
+                })
+            }
+        }
+    };
+}
+
+event_data! {
+    AddressCreated(Value) = "address.created",
+    AddressImported(Value) = "address.imported",
+    AddressUpdated(Value) = "address.updated",
+    AdjustmentCreated(Value) = "adjustment.created",
+    AdjustmentUpdated(Value) = "adjustment.updated",
+    BusinessCreated(Value) = "business.created",
+    BusinessImported(Value) = "business.imported",
+    BusinessUpdated(Value) = "business.updated",
+    CustomerCreated(Value) = "customer.created",
+    CustomerImported(Value) = "customer.imported",
+    CustomerUpdated(Value) = "customer.updated",
+    DiscountCreated(Value) = "discount.created",
+    DiscountImported(Value) = "discount.imported",
+    DiscountUpdated(Value) = "discount.updated",
+    PayoutCreated(Value) = "payout.created",
+    PayoutPaid(Value) = "payout.paid",
+    PriceCreated(Price) = "price.created",
+    PriceImported(Price) = "price.imported",
+    PriceUpdated(Price) = "price.updated",
+    ProductCreated(Product) = "product.created",
+    ProductImported(Product) = "product.imported",
+    ProductUpdated(Product) = "product.updated",
+    ReportCreated(Value) = "report.created",
+    ReportUpdated(Value) = "report.updated",
+    SubscriptionActivated(Value) = "subscription.activated",
+    SubscriptionCanceled(Value) = "subscription.canceled",
+    SubscriptionCreated(Value) = "subscription.created",
+    SubscriptionImported(Value) = "subscription.imported",
+    SubscriptionPastDue(Value) = "subscription.past_due",
+    SubscriptionPaused(Value) = "subscription.paused",
+    SubscriptionResumed(Value) = "subscription.resumed",
+    SubscriptionTrialing(Value) = "subscription.trialing",
+    SubscriptionUpdated(Value) = "subscription.updated",
+    TransactionBilled(Value) = "transaction.billed",
+    TransactionCanceled(Value) = "transaction.canceled",
+    TransactionCompleted(Value) = "transaction.completed",
+    TransactionCreated(Value) = "transaction.created",
+    TransactionPaid(Value) = "transaction.paid",
+    TransactionPastDue(Value) = "transaction.past_due",
+    TransactionPaymentFailed(Value) = "transaction.payment_failed",
+    TransactionReady(Value) = "transaction.ready",
+    TransactionRevised(Value) = "transaction.revised",
+    TransactionUpdated(Value) = "transaction.updated",
+}
+
+// Common attributes of the typed and untyped payloads
+trait EventEntity {
+    fn entity_id(&self) -> Option<&str>;
+    fn updated_at(&self) -> Option<Timestamp>;
+}
+
+impl EventEntity for Product {
+    fn entity_id(&self) -> Option<&str> {
+        self.id().map(|id| id.as_str())
+    }
+
+    fn updated_at(&self) -> Option<Timestamp> {
+        EntityBaseGettersSetters::updated_at(self).cloned()
+    }
+}
+
+impl EventEntity for Price {
+    fn entity_id(&self) -> Option<&str> {
+        self.id().map(|id| id.as_str())
+    }
+
+    fn updated_at(&self) -> Option<Timestamp> {
+        EntityBaseGettersSetters::updated_at(self).cloned()
+    }
+}
+
+impl EventEntity for Value {
+    fn entity_id(&self) -> Option<&str> {
+        self.get("id")?.as_str()
+    }
+
+    fn updated_at(&self) -> Option<Timestamp> {
+        self.get("updated_at")?.as_str()?.parse().ok()
+    }
+}
+
+impl<'de> Deserialize<'de> for EventData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::from_raw(RawEventData::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+impl WebhookEvent {
+    /// Parse the raw body of a webhook request.
+    ///
+    /// Check the signature with [`verify_signature`](super::verify_signature) first,
+    /// or use [`parse_event`](super::parse_event) to do both.
+    pub fn from_slice(raw_body: &[u8]) -> Result<Self, WebhookError> {
+        serde_json::from_slice(raw_body).map_err(|e| WebhookError::InvalidPayload(e.to_string()))
+    }
+
+    /// Unique Paddle ID for this event, prefixed with `evt_`.
+    pub fn event_id(&self) -> &EventId {
+        &self.event_id
+    }
+
+    /// Unique Paddle ID for this notification, prefixed with `ntf_`.
+    pub fn notification_id(&self) -> Option<&NotificationId> {
+        self.notification_id.as_ref()
+    }
+
+    /// When this event occurred.
+    pub fn occurred_at(&self) -> &Timestamp {
+        &self.occurred_at
+    }
+
+    /// Type of the event, for example `product.created`.
+    pub fn event_type(&self) -> &str {
+        self.data.event_type()
+    }
+
+    /// ID of the entity in the payload, for example `sub_01h...`.
+    pub fn entity_id(&self) -> Option<&str> {
+        self.data.entity_id()
+    }
+
+    /// `updated_at` of the entity in the payload.
+    pub fn entity_updated_at(&self) -> Option<Timestamp> {
+        self.data.entity_updated_at()
+    }
+
+    /// Payload of the event.
+    pub fn data(&self) -> &EventData {
+        &self.data
+    }
+
+    pub fn into_data(self) -> EventData {
+        self.data
+    }
+}
@@ -0,0 +1,157 @@
+//! Paddle webhooks.
+//!
+//! Every webhook request carries a `Paddle-Signature` header
+//! (`ts=1671552777;h1=eb4d0dc8...`). Verify it with [`verify_signature`]
+//! against the raw request body **before** parsing the payload.
+//!
+//! [Official document](https://developer.paddle.com/webhooks/signature-verification)
+
+pub mod dedup;
+pub mod event;
+pub mod ordering;
+#[cfg(feature = "webhook-server")]
+pub mod server;
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use self::event::WebhookEvent;
+
+/// Name of the header that contains the webhook signature.
+pub const SIGNATURE_HEADER: &str = "Paddle-Signature";
+
+/// Tolerance recommended by Paddle to protect against replay attacks.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(5);
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Errors that can occur while verifying a webhook.
+#[derive(Debug, PartialEq)]
+pub enum WebhookError {
+    /// The `Paddle-Signature` header has no `ts` or `h1` part, or they can't be parsed.
+    MalformedHeader,
+    /// The timestamp of the signature is outside the tolerance window.
+    TimestampOutOfTolerance {
+        /// Timestamp from the header (Unix seconds)
+        timestamp: u64,
+        /// Local time of the check (Unix seconds)
+        now: u64,
+    },
+    /// None of the `h1` signatures match the request body.
+    InvalidSignature,
+    /// The body isn't a valid webhook event.
+    InvalidPayload(String),
+}
+
+impl std::fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MalformedHeader => write!(f, "malformed {} header", SIGNATURE_HEADER),
+            Self::TimestampOutOfTolerance { timestamp, now } => write!(
+                f,
+                "signature timestamp {} is outside the tolerance window (now {})",
+                timestamp, now
+            ),
+            Self::InvalidSignature => write!(f, "webhook signature doesn't match"),
+            Self::InvalidPayload(e) => write!(f, "invalid webhook payload: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {}
+
+/// Verify the `Paddle-Signature` header of a webhook request.
+///
+/// ### Arguments
+/// `raw_body` - the request body exactly as received, before any parsing\
+/// `header` - value of the `Paddle-Signature` header\
+/// `secret` - the secret key of the notification destination (`pdl_ntfset_...`)\
+/// `tolerance` - how far the signature timestamp may drift from the local clock
+///
+/// The signatures are compared in constant time.
+pub fn verify_signature(
+    raw_body: &[u8],
+    header: &str,
+    secret: &str,
+    tolerance: Duration,
+) -> Result<(), WebhookError> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+
+    for part in header.split(';') {
+        match part.trim().split_once('=') {
+            Some(("ts", ts)) => timestamp = Some(ts),
+            Some(("h1", h1)) => signatures.push(h1),
+            _ => {}
+        }
+    }
+
+    let (Some(ts), false) = (timestamp, signatures.is_empty()) else {
+        return Err(WebhookError::MalformedHeader);
+    };
+    let timestamp: u64 = ts.parse().map_err(|_| WebhookError::MalformedHeader)?;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+
+    if now.abs_diff(timestamp) > tolerance.as_secs() {
+        return Err(WebhookError::TimestampOutOfTolerance { timestamp, now });
+    }
+
+    // Several `h1` values are sent while a secret is being rotated
+    for signature in signatures {
+        let Ok(signature) = hex::decode(signature) else {
+            continue;
+        };
+
+        if signed_payload(raw_body, ts, secret)
+            .verify_slice(&signature)
+            .is_ok()
+        {
+            return Ok(());
+        }
+    }
+
+    Err(WebhookError::InvalidSignature)
+}
+
+/// Verify the signature of a webhook request and parse its body.
+///
+/// See [`verify_signature`] for the arguments.
+pub fn parse_event(
+    raw_body: &[u8],
+    header: &str,
+    secret: &str,
+    tolerance: Duration,
+) -> Result<WebhookEvent, WebhookError> {
+    verify_signature(raw_body, header, secret, tolerance)?;
+    WebhookEvent::from_slice(raw_body)
+}
+
+/// Build a `Paddle-Signature` header value for the body.
+///
+/// Useful to test your webhook handlers without Paddle.
+pub fn sign(raw_body: &[u8], secret: &str, timestamp: u64) -> String {
+    let ts = timestamp.to_string();
+    let h1 = hex::encode(
+        signed_payload(raw_body, &ts, secret)
+            .finalize()
+            .into_bytes(),
+    );
+
+    format!("ts={};h1={}", ts, h1)
+}
+
+// Paddle signs `{ts}:{raw_body}` with HMAC-SHA256
+fn signed_payload(raw_body: &[u8], ts: &str, secret: &str) -> HmacSha256 {
+    // HMAC accepts keys of any length
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC key of any size");
+    mac.update(ts.as_bytes());
+    mac.update(b":");
+    mac.update(raw_body);
+    mac
+}
@@ -0,0 +1,117 @@
+//! Ordering of webhook events.
+//!
+//! Paddle doesn't guarantee the delivery order of webhooks, so an older
+//! `subscription.updated` may arrive after a newer one. [`OrderingGuard`]
+//! remembers the last applied timestamp of every entity and classifies
+//! incoming events.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use super::event::WebhookEvent;
+use crate::timestamp::Timestamp;
+
+/// What to do with an incoming event.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(any(feature = "debug", feature = "logs", test), derive(Debug))]
+pub enum EventOrder {
+    /// The event is newer than the last applied one, or the entity is unknown.
+    Apply,
+    /// A newer state of the entity was already applied.
+    Stale,
+    /// An event of the same type was already applied for this state of the entity.
+    Duplicate,
+}
+
+/// Tracks the last applied timestamp per entity ID.
+///
+/// The timestamp of an event is the `updated_at` of its entity
+/// ([`EntityBaseGettersSetters::updated_at`](crate::entities::EntityBaseGettersSetters::updated_at)),
+/// or `occurred_at` when the entity has none.
+/// Paddle sends several event types for one change, e.g. `subscription.created`
+/// and `subscription.activated`, so each type is applied once per timestamp.
+/// Events without an entity ID are always applied.
+#[derive(Default)]
+pub struct OrderingGuard {
+    applied: Mutex<HashMap<String, Applied>>,
+}
+
+// Last applied timestamp of an entity and the event types applied at it
+struct Applied {
+    timestamp: Timestamp,
+    event_types: HashSet<String>,
+}
+
+impl OrderingGuard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Classify the event without remembering it.
+    pub fn classify(&self, event: &WebhookEvent) -> EventOrder {
+        match entity_version(event) {
+            Some((id, timestamp)) => self.classify_entity(id, event.event_type(), &timestamp),
+            None => EventOrder::Apply,
+        }
+    }
+
+    /// Remember the event as applied. Call it after the event was processed.
+    pub fn record(&self, event: &WebhookEvent) {
+        if let Some((id, timestamp)) = entity_version(event) {
+            self.record_entity(id, event.event_type(), &timestamp);
+        }
+    }
+
+    /// Classify an event of `event_type` about a change of the entity at `timestamp`.
+    pub fn classify_entity<I: AsRef<str>>(
+        &self,
+        entity_id: I,
+        event_type: &str,
+        timestamp: &Timestamp,
+    ) -> EventOrder {
+        match self.lock().get(entity_id.as_ref()) {
+            Some(last) if *timestamp < last.timestamp => EventOrder::Stale,
+            Some(last) if *timestamp == last.timestamp && last.event_types.contains(event_type) => {
+                EventOrder::Duplicate
+            }
+            _ => EventOrder::Apply,
+        }
+    }
+
+    /// Remember an event of `event_type` about a change of the entity at `timestamp`
+    /// as applied. Older timestamps don't replace newer ones.
+    pub fn record_entity<I: AsRef<str>>(
+        &self,
+        entity_id: I,
+        event_type: &str,
+        timestamp: &Timestamp,
+    ) {
+        let mut applied = self.lock();
+        let last = applied
+            .entry(entity_id.as_ref().to_string())
+            .or_insert_with(|| Applied {
+                timestamp: timestamp.clone(),
+                event_types: HashSet::new(),
+            });
+
+        if last.timestamp < *timestamp {
+            last.timestamp = timestamp.clone();
+            last.event_types.clear();
+        }
+        if last.timestamp == *timestamp {
+            last.event_types.insert(event_type.to_string());
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Applied>> {
+        self.applied.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Entity ID and timestamp used to order the event.
+pub fn entity_version(event: &WebhookEvent) -> Option<(&str, Timestamp)> {
+    let timestamp = event
+        .entity_updated_at()
+        .unwrap_or_else(|| event.occurred_at().clone());
+    Some((event.entity_id()?, timestamp))
+}
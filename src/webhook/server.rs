@@ -0,0 +1,204 @@
+//! Webhook endpoint for [axum](https://docs.rs/axum).
+//!
+//! ```no_run
+//! use paddle_api::webhook::{event::WebhookEvent, server::WebhookReceiver};
+//!
+//! # async fn run() {
+//! let receiver = WebhookReceiver::new("pdl_ntfset_...", |event: WebhookEvent| async move {
+//!     println!("{}", event.event_type());
+//!     Ok::<_, std::io::Error>(())
+//! });
+//!
+//! let app: axum::Router = axum::Router::new().nest("/paddle/webhook", receiver.into_router());
+//! # }
+//! ```
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::Router;
+
+use super::dedup::{DedupKey, EventDeduplicator};
+use super::event::WebhookEvent;
+use super::ordering::{entity_version, EventOrder, OrderingGuard};
+use super::{parse_event, WebhookError, DEFAULT_TOLERANCE, SIGNATURE_HEADER};
+
+/// Processes verified webhook events.
+///
+/// Returning an error responds with `500 Internal Server Error`,
+/// so Paddle retries the notification later. The error is logged
+/// with the `logs` feature.
+pub trait WebhookHandler: Send + Sync + 'static {
+    type Error: std::fmt::Display + Send;
+
+    fn handle(&self, event: WebhookEvent) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+impl<F, Fut, E> WebhookHandler for F
+where
+    F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send,
+    E: std::fmt::Display + Send,
+{
+    type Error = E;
+
+    fn handle(&self, event: WebhookEvent) -> impl Future<Output = Result<(), E>> + Send {
+        self(event)
+    }
+}
+
+/// Verifies, parses and dispatches webhook requests to a [`WebhookHandler`].
+pub struct WebhookReceiver<H> {
+    secret: String,
+    tolerance: Duration,
+    handler: H,
+    deduplicator: Option<(Arc<dyn EventDeduplicator>, DedupKey)>,
+    ordering_guard: Option<OrderingGuard>,
+}
+
+impl<H: WebhookHandler> WebhookReceiver<H> {
+    /// Create a new receiver
+    ///
+    /// ### Arguments
+    /// `secret` - the secret key of the notification destination\
+    /// `handler` - called for every verified event
+    pub fn new<T: Into<String>>(secret: T, handler: H) -> Self {
+        Self {
+            secret: secret.into(),
+            tolerance: DEFAULT_TOLERANCE,
+            handler,
+            deduplicator: None,
+            ordering_guard: None,
+        }
+    }
+
+    /// Set how far the signature timestamp may drift from the local clock.
+    /// `Default: 5 seconds`.
+    pub fn set_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Skip events that were already processed.
+    ///
+    /// An event is claimed before the handler runs and remembered only
+    /// after it succeeds, so concurrent deliveries of the event are handled
+    /// once and failed events are still retried by Paddle.
+    pub fn set_deduplicator<D>(mut self, deduplicator: D, key: DedupKey) -> Self
+    where
+        D: EventDeduplicator + 'static,
+    {
+        self.deduplicator = Some((Arc::new(deduplicator), key));
+        self
+    }
+
+    /// Skip events that are older than, or the same as, the last applied
+    /// state of their entity.
+    pub fn set_ordering_guard(mut self, ordering_guard: OrderingGuard) -> Self {
+        self.ordering_guard = Some(ordering_guard);
+        self
+    }
+
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Process a webhook request and return the status code for the response.
+    ///
+    /// - `401 Unauthorized` - missing or invalid signature
+    /// - `400 Bad Request` - the body isn't a webhook event
+    /// - `500 Internal Server Error` - the handler failed, Paddle retries the notification
+    /// - `200 OK` - the event was processed, or skipped as a duplicate or stale event
+    pub async fn receive(&self, headers: &HeaderMap, raw_body: &[u8]) -> StatusCode {
+        let Some(signature) = headers.get(SIGNATURE_HEADER).and_then(|v| v.to_str().ok()) else {
+            return StatusCode::UNAUTHORIZED;
+        };
+
+        let event = match parse_event(raw_body, signature, &self.secret, self.tolerance) {
+            Ok(event) => event,
+            Err(WebhookError::InvalidPayload(_)) => return StatusCode::BAD_REQUEST,
+            Err(_) => return StatusCode::UNAUTHORIZED,
+        };
+
+        // Claimed until processed, so concurrent deliveries are handled once
+        let claim = match &self.deduplicator {
+            Some((deduplicator, key)) => {
+                let key = key.key(&event).to_string();
+                match deduplicator.try_claim(&key) {
+                    Ok(true) => Some((deduplicator.clone(), key)),
+                    Ok(false) => return StatusCode::OK,
+                    Err(_) => return StatusCode::INTERNAL_SERVER_ERROR,
+                }
+            }
+            None => None,
+        };
+
+        let release = |claim: Option<(Arc<dyn EventDeduplicator>, String)>| {
+            if let Some((deduplicator, key)) = claim {
+                let _ = deduplicator.release(&key);
+            }
+        };
+
+        let version = match &self.ordering_guard {
+            Some(guard) => match guard.classify(&event) {
+                EventOrder::Apply => entity_version(&event).map(|(id, timestamp)| {
+                    let event_type = event.event_type().to_string();
+                    (guard, id.to_string(), event_type, timestamp)
+                }),
+                EventOrder::Stale | EventOrder::Duplicate => {
+                    release(claim);
+                    return StatusCode::OK;
+                }
+            },
+            None => None,
+        };
+
+        #[cfg(feature = "logs")]
+        let (event_id, event_type) = (event.event_id().to_string(), event.event_type().to_string());
+
+        #[allow(unused_variables)]
+        if let Err(e) = self.handler.handle(event).await {
+            #[cfg(feature = "logs")]
+            tracing::warn!(event_id, event_type, error = %e, "webhook handler failed");
+
+            release(claim);
+            return StatusCode::INTERNAL_SERVER_ERROR;
+        }
+
+        if let Some((guard, id, event_type, timestamp)) = version {
+            guard.record_entity(&id, &event_type, &timestamp);
+        }
+
+        let Some((deduplicator, key)) = claim else {
+            return StatusCode::OK;
+        };
+
+        match tokio::task::spawn_blocking(move || deduplicator.mark_processed(&key)).await {
+            Ok(Ok(())) => StatusCode::OK,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Router that accepts webhooks with `POST /`.
+    ///
+    /// Mount it with [`Router::nest`] or [`Router::merge`].
+    pub fn into_router<S>(self) -> Router<S> {
+        Router::new()
+            .route("/", post(receive_webhook::<H>))
+            .with_state(Arc::new(self))
+    }
+}
+
+/// Axum handler, expects the receiver as state.
+pub async fn receive_webhook<H: WebhookHandler>(
+    State(receiver): State<Arc<WebhookReceiver<H>>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    receiver.receive(&headers, &body).await
+}
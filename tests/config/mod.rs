@@ -1,4 +1,6 @@
+use axum::Router;
 use once_cell::sync::Lazy;
+use paddle_api::id::{PriceId, ProductId};
 use std::{env, sync::Arc};
 
 /// Environment variables:
@@ -6,27 +8,137 @@ use std::{env, sync::Arc};
 /// `PADDLE_API_AUTH`
 /// `PADDLE_PRODUCT_ID`
 /// `PADDLE_PRICE_ID`
-
+#[allow(dead_code)]
 pub struct Config {
     pub url: String,
     pub auth: String,
-    #[allow(dead_code)]
-    pub product_id: String,
-    #[allow(dead_code)]
-    pub price_id: String,
+    pub product_id: ProductId,
+    pub price_id: PriceId,
 }
 
+#[allow(dead_code)]
 impl Config {
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
         dotenv::dotenv()?;
         Ok(Self {
             url: env::var("PADDLE_API_URL")?,
             auth: env::var("PADDLE_API_AUTH")?,
-            product_id: env::var("PADDLE_PRODUCT_ID")?,
-            price_id: env::var("PADDLE_PRICE_ID")?,
+            product_id: env::var("PADDLE_PRODUCT_ID")?.parse()?,
+            price_id: env::var("PADDLE_PRICE_ID")?.parse()?,
         })
     }
 }
 
+#[allow(dead_code)]
 pub static CONFIG: Lazy<Arc<Config>> =
     Lazy::new(|| Arc::new(Config::new().expect("Failed to load config")));
+
+/// Request ID of the canned responses.
+#[allow(dead_code)]
+pub const REQUEST_ID: &str = "a1b2c3d4-e5f6-4a7b-8c9d-0e1f2a3b4c5d";
+
+/// Empty last page of `GET /products`.
+#[allow(dead_code)]
+pub const PRODUCTS: &str = r#"{
+    "data": [],
+    "meta": {
+        "request_id": "a1b2c3d4-e5f6-4a7b-8c9d-0e1f2a3b4c5d",
+        "pagination": { "per_page": 50, "next": "", "has_more": false, "estimated_total": 0 }
+    }
+}"#;
+
+/// `not_found` error of `GET /products/{id}`.
+#[allow(dead_code)]
+pub const NOT_FOUND: &str = r#"{
+    "error": {
+        "type": "request_error",
+        "code": "not_found",
+        "detail": "Entity pro_01gsz4t5hdjse780zja8vvr7jg not found",
+        "documentation_url": "https://developer.paddle.com/errors/shared/not_found"
+    },
+    "meta": { "request_id": "a1b2c3d4-e5f6-4a7b-8c9d-0e1f2a3b4c5d" }
+}"#;
+
+/// Serve the router on a free local port, returns its base URL.
+#[allow(dead_code)]
+pub async fn serve(router: Router) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    url
+}
+
+/// Mock server seeded for the product and price tests:
+/// a standard product with a price, a SaaS product, an archived
+/// SaaS product and two custom products.
+#[cfg(feature = "mock-server")]
+#[allow(dead_code)]
+pub struct Mock {
+    pub server: paddle_api::mock::MockServer,
+    pub client: paddle_api::Client,
+    pub product_ids: Vec<ProductId>,
+    pub price_id: PriceId,
+}
+
+#[cfg(feature = "mock-server")]
+#[allow(dead_code)]
+impl Mock {
+    pub async fn start() -> Result<Self, Box<dyn std::error::Error>> {
+        use paddle_api::entities::price::{Price, UnitPrice};
+        use paddle_api::entities::product::{Product, ProductTaxCategory};
+        use paddle_api::entities::{EntityBaseGettersSetters, EntityStatus, EntityType};
+
+        let server = paddle_api::mock::MockServer::start().await?;
+        let client = paddle_api::Client::new(&server.url(), "pdl_sdbx_apikey")?;
+        let mut product_ids = Vec::new();
+
+        for (name, p_type, tax_category) in [
+            ("Basic", EntityType::Standard, ProductTaxCategory::Standard),
+            ("Pro", EntityType::Standard, ProductTaxCategory::SaaS),
+            ("Legacy", EntityType::Standard, ProductTaxCategory::SaaS),
+            ("Acme", EntityType::Custom, ProductTaxCategory::SaaS),
+            ("Globex", EntityType::Custom, ProductTaxCategory::SaaS),
+        ] {
+            let r = client
+                .create_product(
+                    Product::default()
+                        .set_name(name)
+                        .set_description(format!("{} plan", name))
+                        .set_p_type(p_type)
+                        .set_tax_category(tax_category),
+                )
+                .await?;
+            product_ids.push(r.data().id().unwrap().clone());
+        }
+
+        client
+            .update_product(
+                &product_ids[2],
+                Product::default().set_status(EntityStatus::Archived),
+            )
+            .await?;
+
+        let r = client
+            .create_price(
+                Price::default()
+                    .set_product_id(product_ids[0].clone())
+                    .set_description("Basic monthly")
+                    .set_unit_price(UnitPrice::new("1000", "USD")),
+            )
+            .await?;
+        let price_id = r.data().id().unwrap().clone();
+
+        Ok(Self {
+            server,
+            client,
+            product_ids,
+            price_id,
+        })
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_ids[0]
+    }
+}
@@ -0,0 +1,169 @@
+mod config;
+
+use axum::extract::RawQuery;
+use axum::http::StatusCode;
+use axum::response::AppendHeaders;
+use axum::routing::get;
+use axum::{Json, Router};
+use futures::future::BoxFuture;
+use paddle_api::cassette::{Cassette, CassetteMode};
+use paddle_api::entities::product::list::ListProductsParams;
+use paddle_api::entities::{BaseListParamsGettersSetters, EntityBaseGettersSetters};
+use paddle_api::transport::{self, ReqwestTransport, Transport};
+use paddle_api::{Client, Error};
+use reqwest::{Request, Response};
+use serde_json::{json, Value};
+
+const KEY: &str = "pdl_sdbx_apikey_01gtgztp8fy5jqgjtqsg6dq5qv";
+
+// Echoes the query in the product name
+async fn products(RawQuery(query): RawQuery) -> Json<Value> {
+    Json(json!({
+        "data": [{
+            "id": "pro_01gsz4t5hdjse780zja8vvr7jg",
+            "name": query.unwrap_or_default(),
+            "custom_data": { "email": "jo@example.com", "plan": "pro" }
+        }],
+        "meta": { "request_id": config::REQUEST_ID }
+    }))
+}
+
+#[tokio::test]
+async fn test_cassette_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let path =
+        std::env::temp_dir().join(format!("paddle_api_cassette_{}.json", std::process::id()));
+
+    let url = config::serve(Router::new().route("/products", get(products))).await;
+
+    // Record
+    let transport = ReqwestTransport::new(reqwest::Client::new());
+    let cassette = Cassette::record(&path, transport).set_scrub_fields(["email"]);
+    assert_eq!(cassette.mode(), CassetteMode::Record);
+
+    let client = Client::builder(&url, KEY).set_transport(cassette).build()?;
+    let params = ListProductsParams::default()
+        .set_per_page(10)
+        .set_after("pro_01".parse()?);
+    let r = client.get_list_products(params).await?;
+
+    // The caller still gets the unscrubbed response
+    let custom_data = r.data()[0].product().custom_data().unwrap();
+    assert_eq!(custom_data["email"], "jo@example.com");
+
+    let recorded = std::fs::read_to_string(&path)?;
+    assert!(!recorded.contains(KEY));
+    assert!(!recorded.contains("jo@example.com"));
+    assert!(recorded.contains("[REDACTED]"));
+
+    // Replay without a server, with the query parameters in another order
+    let client = Client::builder("http://127.0.0.1:9/", KEY)
+        .set_transport(Cassette::replay(&path)?)
+        .build()?;
+    let params = ListProductsParams::default()
+        .set_after("pro_01".parse()?)
+        .set_per_page(10);
+    let r = client.get_list_products(params).await?;
+
+    let product = r.data()[0].product();
+    assert_eq!(product.name(), Some("after=pro_01&per_page=10"));
+    assert_eq!(product.custom_data().unwrap()["email"], "[REDACTED]");
+    assert_eq!(product.custom_data().unwrap()["plan"], "pro");
+
+    // Every interaction is replayed once
+    let params = ListProductsParams::default()
+        .set_after("pro_01".parse()?)
+        .set_per_page(10);
+    match client.get_list_products(params).await {
+        Err(Error::Validation(e)) => {
+            assert!(e.contains("no recorded interaction for GET /products"))
+        }
+        r => panic!("expected an unmatched request, got {:?}", r),
+    }
+
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_cassette_t_1() {
+    let path = std::env::temp_dir().join("paddle_api_cassette_missing.json");
+
+    assert!(matches!(Cassette::replay(path), Err(Error::Io(_))));
+}
+
+// Answers every request without a network
+struct Offline;
+
+impl Transport for Offline {
+    fn execute(&self, _: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        Box::pin(async {
+            Ok(transport::response(
+                StatusCode::OK,
+                r#"{"data":[],"meta":{"request_id":"offline"}}"#,
+            ))
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_cassette_t_2() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::temp_dir().join(format!(
+        "paddle_api_cassette_offline_{}.json",
+        std::process::id()
+    ));
+
+    // Recorded through the given transport, the host is never contacted
+    let client = Client::builder("http://paddle.invalid/", KEY)
+        .set_transport(Cassette::record(&path, Offline))
+        .build()?;
+    let r = client
+        .get_list_products(ListProductsParams::default())
+        .await?;
+    assert_eq!(r.meta().request_id(), "offline");
+    assert!(std::fs::read_to_string(&path)?.contains("offline"));
+
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cassette_t_3() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::temp_dir().join(format!(
+        "paddle_api_cassette_cookies_{}.json",
+        std::process::id()
+    ));
+
+    let url = config::serve(Router::new().route(
+        "/cookies",
+        get(|| async {
+            (
+                AppendHeaders([("set-cookie", "a=1"), ("set-cookie", "b=2")]),
+                "ok",
+            )
+        }),
+    ))
+    .await;
+    let request = || {
+        Request::new(
+            reqwest::Method::GET,
+            format!("{url}cookies").parse().unwrap(),
+        )
+    };
+
+    let cassette = Cassette::record(&path, ReqwestTransport::new(reqwest::Client::new()));
+    cassette.execute(request()).await?;
+
+    // Repeated headers are replayed with every value
+    let cassette = Cassette::replay(&path)?;
+    assert_eq!(cassette.mode(), CassetteMode::Replay);
+
+    let response = cassette.execute(request()).await?;
+    let cookies: Vec<_> = response.headers().get_all("set-cookie").iter().collect();
+    assert_eq!(cookies, ["a=1", "b=2"]);
+
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
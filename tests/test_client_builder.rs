@@ -0,0 +1,147 @@
+mod config;
+
+use std::time::Duration;
+
+use axum::http::HeaderMap;
+use axum::routing::get;
+use axum::{Json, Router};
+use paddle_api::builder::DEFAULT_USER_AGENT;
+use paddle_api::entities::product::list::ListProductsParams;
+use paddle_api::{Client, ClientBuilder, Error};
+use serde_json::{json, Value};
+
+// Echoes the request headers in `meta.request_id`
+async fn products(headers: HeaderMap) -> Json<Value> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .map_or("", |v| v.to_str().unwrap())
+            .to_string()
+    };
+
+    Json(json!({
+        "data": [],
+        "meta": {
+            "request_id": format!("{}|{}", header("user-agent"), header("paddle-version")),
+        }
+    }))
+}
+
+async fn slow() -> Json<Value> {
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    Json(json!({ "data": [], "meta": { "request_id": "" } }))
+}
+
+async fn serve() -> String {
+    config::serve(
+        Router::new()
+            .route("/products", get(products))
+            .route("/slow/products", get(slow)),
+    )
+    .await
+}
+
+async fn request_id(client: &Client) -> Result<String, Error> {
+    let r = client
+        .get_list_products(ListProductsParams::default())
+        .await?;
+    Ok(r.meta().request_id().to_string())
+}
+
+#[tokio::test]
+async fn test_client_builder_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let url = serve().await;
+
+    let client = Client::new(&url, "pdl_sdbx_apikey")?;
+    assert_eq!(
+        request_id(&client).await?,
+        format!("{}|", DEFAULT_USER_AGENT)
+    );
+
+    let client = Client::builder(&url, "pdl_sdbx_apikey")
+        .set_user_agent("my-app/1.0")
+        .set_paddle_version("1")
+        .set_client(reqwest::Client::new())
+        .build()?;
+    assert_eq!(request_id(&client).await?, "my-app/1.0|1");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_client_builder_t_1() -> Result<(), Box<dyn std::error::Error>> {
+    let url = serve().await;
+    let client = ClientBuilder::new(&format!("{}slow/", url), "pdl_sdbx_apikey")
+        .set_timeout(Duration::from_millis(100))
+        .build()?;
+
+    match request_id(&client).await {
+        Err(Error::Http(e)) => assert!(e.is_timeout()),
+        r => panic!("expected a timeout, got {:?}", r),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_client_builder_t_2() -> Result<(), Box<dyn std::error::Error>> {
+    let url = serve().await;
+
+    // The proxy receives the request for the unreachable host
+    let client = ClientBuilder::new("http://paddle.invalid/", "pdl_sdbx_apikey")
+        .set_proxy(reqwest::Proxy::http(&url)?)
+        .set_connect_timeout(Duration::from_secs(1))
+        .build()?;
+    assert_eq!(
+        request_id(&client).await?,
+        format!("{}|", DEFAULT_USER_AGENT)
+    );
+
+    let r = ClientBuilder::new(&url, "pdl_sdbx_apikey")
+        .set_client(reqwest::Client::new())
+        .set_connect_timeout(Duration::from_secs(1))
+        .build();
+    assert!(matches!(r, Err(Error::Validation(_))));
+
+    let r = ClientBuilder::new(&url, "pdl_sdbx_apikey")
+        .set_client(reqwest::Client::new())
+        .set_read_timeout(Duration::from_secs(1))
+        .build();
+    assert!(matches!(r, Err(Error::Validation(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_client_builder_t_3() -> Result<(), Box<dyn std::error::Error>> {
+    let url = serve().await;
+    let client = ClientBuilder::new(&format!("{}slow/", url), "pdl_sdbx_apikey")
+        .set_read_timeout(Duration::from_millis(100))
+        .build()?;
+
+    match request_id(&client).await {
+        Err(Error::Http(e)) => assert!(e.is_timeout()),
+        r => panic!("expected a timeout, got {:?}", r),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_client_from_env_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let url = serve().await;
+
+    std::env::set_var("PADDLE_API_URL", &url);
+    std::env::set_var("PADDLE_API_AUTH", "pdl_sdbx_apikey");
+
+    let client = Client::from_env()?;
+    assert_eq!(
+        request_id(&client).await?,
+        format!("{}|", DEFAULT_USER_AGENT)
+    );
+
+    std::env::remove_var("PADDLE_API_AUTH");
+    assert!(matches!(Client::from_env(), Err(Error::Validation(_))));
+
+    Ok(())
+}
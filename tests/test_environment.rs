@@ -0,0 +1,58 @@
+use paddle_api::{Client, Environment, Error};
+use url::Url;
+
+#[test]
+fn test_environment_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(
+        Environment::Sandbox.url().as_str(),
+        "https://sandbox-api.paddle.com/"
+    );
+    assert_eq!(
+        Environment::Production.url().as_str(),
+        "https://api.paddle.com/"
+    );
+
+    assert_eq!(
+        "https://sandbox-api.paddle.com".parse::<Environment>()?,
+        Environment::Sandbox
+    );
+    assert_eq!(
+        "production".parse::<Environment>()?,
+        Environment::Production
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_environment_t_1() -> Result<(), Box<dyn std::error::Error>> {
+    // Without the trailing slash `join` would replace `v1`
+    let environment = Environment::Custom(Url::parse("http://localhost:8080/paddle/v1")?);
+    let url = environment.url();
+
+    assert_eq!(url.as_str(), "http://localhost:8080/paddle/v1/");
+    assert_eq!(
+        url.join("products")?.as_str(),
+        "http://localhost:8080/paddle/v1/products"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_environment_t_2() -> Result<(), Box<dyn std::error::Error>> {
+    Client::with_environment(Environment::Sandbox, "pdl_sdbx_apikey")?;
+    Client::with_environment(Environment::Production, "pdl_live_apikey")?;
+    Client::new("https://api.paddle.com", "legacy_apikey")?;
+
+    let r = Client::with_environment(Environment::Sandbox, "pdl_live_apikey");
+    assert!(matches!(r, Err(Error::Validation(_))));
+
+    let r = Client::new("https://api.paddle.com", "pdl_sdbx_apikey");
+    assert!(matches!(r, Err(Error::Validation(_))));
+
+    let custom = Environment::Custom(Url::parse("http://localhost:8080/")?);
+    Client::with_environment(custom, "pdl_live_apikey")?;
+
+    Ok(())
+}
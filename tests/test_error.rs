@@ -0,0 +1,228 @@
+mod config;
+
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use paddle_api::entities::product::list::ListProductsParams;
+use paddle_api::entities::BaseListParamsGettersSetters;
+use paddle_api::error::{ErrorCode, MAX_ERROR_BODY};
+use paddle_api::{Client, Error};
+use serde_json::{json, Value};
+
+async fn product(Path(id): Path<String>) -> (StatusCode, Json<Value>) {
+    match id.as_str() {
+        "pro_missing" => (
+            StatusCode::NOT_FOUND,
+            Json(json!({
+                "error": {
+                    "type": "request_error",
+                    "code": "not_found",
+                    "detail": "Entity pro_missing not found",
+                    "documentation_url": "https://developer.paddle.com/v1/errors/shared/not_found"
+                },
+                "meta": { "request_id": "9e2f0e4b-6b3c-4b8e-8a43-1c6e3f1e0f5a" }
+            })),
+        ),
+        _ => (
+            StatusCode::OK,
+            Json(json!({
+                "data": { "id": "pro_01", "name": "Pro" },
+                "meta": { "request_id": 42 }
+            })),
+        ),
+    }
+}
+
+async fn price(Path(id): Path<String>) -> (StatusCode, [(&'static str, &'static str); 1], String) {
+    match id.as_str() {
+        "pri_proxy" => (
+            StatusCode::BAD_GATEWAY,
+            [("content-type", "text/html")],
+            format!(
+                "<html><body>{}</body></html>",
+                "502 Bad Gateway ".repeat(200)
+            ),
+        ),
+        "pri_gone" => (
+            StatusCode::NOT_FOUND,
+            [("content-type", "text/plain")],
+            "404 page not found".to_string(),
+        ),
+        _ => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            [("content-type", "application/json")],
+            r#"{"message":"upstream failed"}"#.to_string(),
+        ),
+    }
+}
+
+async fn products() -> (StatusCode, Json<Value>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(json!({
+            "error": {
+                "type": "request_error",
+                "code": "invalid_field",
+                "detail": "Request does not pass validation.",
+                "documentation_url": "https://developer.paddle.com/v1/errors/shared/invalid_field",
+                "errors": [{ "field": "per_page", "message": "must be less than or equal to 200" }]
+            },
+            "meta": { "request_id": "5f9a7c3e-2d1b-4e6a-8b0c-7a3d9e1f2b4c" }
+        })),
+    )
+}
+
+async fn client() -> Client {
+    let url = config::serve(
+        Router::new()
+            .route("/products", get(products))
+            .route("/products/{id}", get(product))
+            .route("/prices/{id}", get(price)),
+    )
+    .await;
+
+    Client::new(&url, "pdl_sdbx_apikey").unwrap()
+}
+
+#[tokio::test]
+async fn test_error_t_0() {
+    let client = client().await;
+
+    let error = client
+        .get_product::<Vec<_>, String>(&"pro_missing".parse().unwrap(), None)
+        .await
+        .unwrap_err();
+
+    let Error::Api {
+        status,
+        error,
+        request_id,
+    } = &error
+    else {
+        panic!("Expected an API error: {}", error);
+    };
+
+    assert!(error.code.is_not_found() && !error.code.is_retryable());
+
+    assert_eq!(*status, StatusCode::NOT_FOUND);
+    assert_eq!(error.code, ErrorCode::NotFound);
+    assert_eq!(
+        request_id.as_deref(),
+        Some("9e2f0e4b-6b3c-4b8e-8a43-1c6e3f1e0f5a")
+    );
+}
+
+#[tokio::test]
+async fn test_error_t_1() {
+    let client = client().await;
+
+    let error = client
+        .get_list_products(ListProductsParams::default().set_per_page(500))
+        .await
+        .unwrap_err();
+
+    let errors = error
+        .api_error()
+        .and_then(|e| e.errors.as_ref())
+        .expect("Expected validation errors");
+
+    assert_eq!(errors[0].field, "per_page");
+    assert_eq!(error.code(), Some(&ErrorCode::InvalidField));
+    assert!(!error.is_retryable() && !error.is_auth_error() && !error.is_not_found());
+    assert_eq!(error.status(), Some(StatusCode::BAD_REQUEST));
+}
+
+#[tokio::test]
+async fn test_error_t_2() {
+    let client = client().await;
+
+    let error = client
+        .get_product::<Vec<_>, String>(&"pro_01".parse().unwrap(), None)
+        .await
+        .unwrap_err();
+
+    let Error::Decode { path, body, .. } = &error else {
+        panic!("Expected a decode error: {}", error);
+    };
+
+    assert_eq!(path, "meta.request_id");
+    assert!(body.contains(r#""request_id":42"#));
+}
+
+#[test]
+fn test_error_code_t_0() {
+    for (code, retryable, auth, not_found) in [
+        ("too_many_requests", true, false, false),
+        ("internal_error", true, false, false),
+        ("concurrent_modification", false, false, false),
+        ("forbidden", false, true, false),
+        ("invalid_token", false, true, false),
+        ("not_found", false, false, true),
+        ("entity_archived", false, false, false),
+    ] {
+        let c: ErrorCode = code.parse().unwrap();
+
+        assert_eq!(c.as_str(), code);
+        assert_eq!(c.is_retryable(), retryable);
+        assert_eq!(c.is_auth_error(), auth);
+        assert_eq!(c.is_not_found(), not_found);
+    }
+
+    let c: ErrorCode = "brand_new_code".parse().unwrap();
+    assert_eq!(c, ErrorCode::Other("brand_new_code".to_string()));
+    assert_eq!(c.to_string(), "brand_new_code");
+}
+
+#[tokio::test]
+async fn test_error_t_3() {
+    let client = client().await;
+
+    let error = client
+        .get_price::<Vec<_>, String>(&"pri_proxy".parse().unwrap(), None)
+        .await
+        .unwrap_err();
+
+    let Error::UnexpectedResponse {
+        status,
+        headers,
+        body,
+    } = &error
+    else {
+        panic!("Expected an unexpected response: {}", error);
+    };
+
+    assert_eq!(*status, StatusCode::BAD_GATEWAY);
+    assert_eq!(headers["content-type"], "text/html");
+    assert!(body.starts_with("<html><body>502 Bad Gateway"));
+    assert!(body.len() <= MAX_ERROR_BODY && body.ends_with('…'));
+    assert!(error.is_retryable());
+
+    let error = client
+        .get_price::<Vec<_>, String>(&"pri_json".parse().unwrap(), None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        &error,
+        Error::UnexpectedResponse { status, body, .. }
+            if *status == StatusCode::INTERNAL_SERVER_ERROR && body == r#"{"message":"upstream failed"}"#
+    ));
+}
+
+#[tokio::test]
+async fn test_error_t_4() {
+    let client = client().await;
+
+    let error = client
+        .get_price::<Vec<_>, String>(&"pri_gone".parse().unwrap(), None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        &error,
+        Error::UnexpectedResponse { status, .. } if *status == StatusCode::NOT_FOUND
+    ));
+    assert!(error.is_not_found());
+    assert!(!error.is_retryable());
+}
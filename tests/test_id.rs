@@ -0,0 +1,88 @@
+use paddle_api::entities::{
+    price::{list::ListPricesParams, Price},
+    BaseListParamsGettersSetters, EntityBaseGettersSetters,
+};
+use paddle_api::id::{id_kind, IdKind, PriceId, ProductId, SubscriptionId};
+use paddle_api::Error;
+
+#[test]
+fn test_id_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let id: ProductId = "pro_01gsz4t5hdjse780zja8vvr7jg".parse()?;
+
+    assert_eq!(id.as_str(), "pro_01gsz4t5hdjse780zja8vvr7jg");
+    assert_eq!(id.to_string(), "pro_01gsz4t5hdjse780zja8vvr7jg");
+    assert_eq!(ProductId::KIND, IdKind::Product);
+    assert_eq!(
+        serde_json::to_string(&id)?,
+        r#""pro_01gsz4t5hdjse780zja8vvr7jg""#
+    );
+    assert_eq!(
+        serde_json::from_str::<ProductId>(r#""pro_01gsz4t5hdjse780zja8vvr7jg""#)?,
+        id
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_id_t_1() {
+    let e = "pri_01gsz8x8sawmvhz1pv30nge1ke"
+        .parse::<ProductId>()
+        .unwrap_err();
+    assert_eq!(e.expected(), IdKind::Product);
+    assert_eq!(
+        e.to_string(),
+        "expected a `pro_` ID, got a `pri_` ID: pri_01gsz8x8sawmvhz1pv30nge1ke"
+    );
+
+    assert!("sub_".parse::<SubscriptionId>().is_err());
+    assert!("sub_01H7ZCGM".parse::<SubscriptionId>().is_err());
+    assert!("01gsz8x8sawmvhz1pv30nge1ke".parse::<PriceId>().is_err());
+
+    let e: Error = "pro_01".parse::<PriceId>().unwrap_err().into();
+    assert!(matches!(e, Error::Validation(_)));
+
+    assert!(serde_json::from_str::<Price>(r#"{"id":"pro_01gsz4t5hdjse780zja8vvr7jg"}"#).is_err());
+}
+
+#[test]
+fn test_id_t_2() {
+    assert_eq!(
+        id_kind("txn_01h04vsbhqc62t8hmd4z3b578c"),
+        Some(IdKind::Transaction)
+    );
+    assert_eq!(
+        id_kind("ctm_01h8441jn5pcwrfhwh78jqt8hk"),
+        Some(IdKind::Customer)
+    );
+    assert_eq!(
+        id_kind("ntfset_01gkpjp8bkm3tm53kdgkx6sms7"),
+        Some(IdKind::NotificationSetting)
+    );
+    assert_eq!(id_kind("xyz_01h04vsbhqc62t8hmd4z3b578c"), None);
+    assert_eq!(id_kind("txn_01H04VSB"), None);
+    assert_eq!(id_kind("txn"), None);
+}
+
+#[test]
+fn test_id_t_3() -> Result<(), Box<dyn std::error::Error>> {
+    let price: Price = serde_json::from_str(
+        r#"{"id":"pri_01gsz8x8sawmvhz1pv30nge1ke","product_id":"pro_01gsz4t5hdjse780zja8vvr7jg"}"#,
+    )?;
+    assert_eq!(price.id().unwrap(), "pri_01gsz8x8sawmvhz1pv30nge1ke");
+    assert_eq!(
+        price.product_id().unwrap().to_string(),
+        "pro_01gsz4t5hdjse780zja8vvr7jg"
+    );
+
+    let params = ListPricesParams::default().set_id([
+        "pri_01gsz8x8sawmvhz1pv30nge1ke".parse()?,
+        "pri_01gsz8z1q1n00f12qt82y31smh".parse()?,
+    ]);
+    assert_eq!(
+        serde_qs::to_string(&params)?,
+        "id=pri_01gsz8x8sawmvhz1pv30nge1ke%2Cpri_01gsz8z1q1n00f12qt82y31smh"
+    );
+
+    Ok(())
+}
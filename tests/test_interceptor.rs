@@ -0,0 +1,81 @@
+mod config;
+
+use std::sync::{Arc, Mutex};
+
+use axum::http::HeaderMap;
+use axum::routing::get;
+use axum::{Json, Router};
+use paddle_api::entities::price::list::ListPricesParams;
+use paddle_api::entities::product::list::ListProductsParams;
+use paddle_api::interceptor::Interceptor;
+use paddle_api::Client;
+use reqwest::header::HeaderValue;
+use reqwest::{Request, Response};
+use serde_json::{json, Value};
+
+// Echoes `X-Correlation-Id` in `meta.request_id`
+async fn list(headers: HeaderMap) -> Json<Value> {
+    let correlation_id = headers
+        .get("x-correlation-id")
+        .map_or("", |v| v.to_str().unwrap());
+
+    Json(json!({ "data": [], "meta": { "request_id": correlation_id } }))
+}
+
+struct CorrelationId(&'static str);
+
+impl Interceptor for CorrelationId {
+    fn before_request(&self, request: &mut Request) {
+        request
+            .headers_mut()
+            .insert("x-correlation-id", HeaderValue::from_static(self.0));
+    }
+}
+
+#[derive(Clone, Default)]
+struct Audit(Arc<Mutex<Vec<String>>>);
+
+impl Interceptor for Audit {
+    fn before_request(&self, request: &mut Request) {
+        let path = request.url().path().to_string();
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", request.method(), path));
+    }
+
+    fn after_response(&self, response: &Response) {
+        self.0.lock().unwrap().push(response.status().to_string());
+    }
+}
+
+#[tokio::test]
+async fn test_interceptor_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let url = config::serve(
+        Router::new()
+            .route("/products", get(list))
+            .route("/prices", get(list)),
+    )
+    .await;
+
+    let audit = Audit::default();
+    let client = Client::builder(&url, "pdl_sdbx_apikey")
+        .add_interceptor(CorrelationId("first"))
+        .add_interceptor(CorrelationId("second"))
+        .add_interceptor(audit.clone())
+        .build()?;
+
+    let products = client
+        .get_list_products(ListProductsParams::default())
+        .await?;
+    let prices = client.get_list_prices(ListPricesParams::default()).await?;
+
+    assert_eq!(products.meta().request_id(), "second");
+    assert_eq!(prices.meta().request_id(), "second");
+    assert_eq!(
+        *audit.0.lock().unwrap(),
+        ["GET /products", "200 OK", "GET /prices", "200 OK"]
+    );
+
+    Ok(())
+}
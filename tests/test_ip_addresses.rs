@@ -0,0 +1,39 @@
+mod config;
+
+use config::*;
+
+use std::net::IpAddr;
+
+use paddle_api::entities::ip_addresses::IpAddressesResponse;
+use paddle_api::Client;
+
+#[tokio::test]
+async fn test_get_ip_addresses_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let config = CONFIG.clone();
+    let client = Client::new(&config.url, &config.auth)?;
+    let r = client.get_ip_addresses().await?;
+
+    println!("Get IP addresses response: {:#?}", r);
+
+    assert!(!r.data().ipv4_cidrs().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_ip_addresses_contains_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let r: IpAddressesResponse = serde_json::from_str(
+        r#"{
+            "data": { "ipv4_cidrs": ["34.232.58.13/32", "34.195.105.136/32", "10.0.0.0/24"] },
+            "meta": { "request_id": "c3a2b5e8-5b7a-4a3f-9c7e-3f2b1a0e9d8c" }
+        }"#,
+    )?;
+
+    let ips = r.data();
+
+    assert!(ips.contains(&"34.232.58.13".parse::<IpAddr>()?));
+    assert!(ips.contains(&"10.0.0.42".parse::<IpAddr>()?));
+    assert!(ips.contains(&"::ffff:34.195.105.136".parse::<IpAddr>()?));
+    assert!(!ips.contains(&"34.232.58.14".parse::<IpAddr>()?));
+    assert!(!ips.contains(&"2001:db8::1".parse::<IpAddr>()?));
+    Ok(())
+}
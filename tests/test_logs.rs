@@ -0,0 +1,160 @@
+#![cfg(feature = "logs")]
+
+mod config;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::Router;
+use paddle_api::entities::product::list::ListProductsParams;
+use paddle_api::entities::product::ProductTaxCategory;
+use paddle_api::retry::RetryPolicy;
+use paddle_api::Client;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+const KEY: &str = "pdl_sdbx_apikey_01gtgztp8fy5jqgjtqsg6dq5qv";
+
+// Fields of the `paddle.request` spans
+#[derive(Clone, Default)]
+struct Spans(Arc<Mutex<HashMap<u64, HashMap<String, String>>>>);
+
+struct Fields<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for Fields<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Spans {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _: Context<'_, S>) {
+        if attrs.metadata().name() == "paddle.request" {
+            let mut spans = self.0.lock().unwrap();
+            attrs.record(&mut Fields(spans.entry(id.into_u64()).or_default()));
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _: Context<'_, S>) {
+        if let Some(fields) = self.0.lock().unwrap().get_mut(&id.into_u64()) {
+            values.record(&mut Fields(fields));
+        }
+    }
+}
+
+// Fields of the logged events
+#[derive(Clone, Default)]
+struct Events(Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+impl<S: Subscriber> Layer<S> for Events {
+    fn on_event(&self, event: &tracing::Event<'_>, _: Context<'_, S>) {
+        let mut fields = HashMap::new();
+        event.record(&mut Fields(&mut fields));
+        self.0.lock().unwrap().push(fields);
+    }
+}
+
+#[tokio::test]
+async fn test_logs_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let spans = Spans::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(spans.clone()));
+
+    // Fails once with 503, then succeeds
+    let hits = Arc::new(AtomicUsize::new(0));
+    let app = Router::new().route(
+        "/products",
+        get(move || async move {
+            match hits.fetch_add(1, Ordering::SeqCst) {
+                0 => (StatusCode::SERVICE_UNAVAILABLE, "Service Unavailable"),
+                _ => (StatusCode::OK, config::PRODUCTS),
+            }
+        }),
+    );
+    let client = Client::builder(&config::serve(app).await, KEY)
+        .set_retry_policy(RetryPolicy::new().set_base_delay(Duration::from_millis(1)))
+        .build()?;
+    client
+        .get_list_products(
+            ListProductsParams::default().set_tax_category([ProductTaxCategory::SaaS]),
+        )
+        .await?;
+
+    let spans = spans.0.lock().unwrap();
+    let fields = spans.values().next().unwrap();
+
+    assert_eq!(spans.len(), 1);
+    assert_eq!(fields["http.request.method"], "GET");
+    assert_eq!(fields["url.path"], "/products");
+    assert_eq!(fields["paddle.entity"], "products");
+    assert_eq!(fields["http.response.status_code"], "200");
+    assert_eq!(fields["attempt"], "2");
+    assert_eq!(fields["paddle.request_id"], config::REQUEST_ID);
+    assert!(fields.contains_key("latency_ms"));
+    assert!(fields
+        .values()
+        .all(|v| !v.contains(KEY) && !v.contains("saas")));
+
+    Ok(())
+}
+
+#[cfg(feature = "webhook-server")]
+#[tokio::test]
+async fn test_logs_t_1() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use axum::http::HeaderMap;
+    use paddle_api::webhook::event::WebhookEvent;
+    use paddle_api::webhook::server::WebhookReceiver;
+    use paddle_api::webhook::{sign, SIGNATURE_HEADER};
+
+    const SECRET: &str = "pdl_ntfset_01gkpjp8bkm3tm53kdgkx6sms7_6h3qd3uFSi9YCD3OLYAShQI90XTI5vEI";
+    const EVENT: &str = r#"{
+        "event_id": "evt_01",
+        "event_type": "subscription.updated",
+        "occurred_at": "2023-08-18T10:56:09.723118Z",
+        "notification_id": "ntf_01",
+        "data": { "id": "sub_01" }
+    }"#;
+
+    let events = Events::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(events.clone()));
+
+    let receiver = WebhookReceiver::new(SECRET, |_: WebhookEvent| async {
+        Err("database unavailable")
+    });
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        SIGNATURE_HEADER,
+        sign(EVENT.as_bytes(), SECRET, now.as_secs())
+            .parse()
+            .unwrap(),
+    );
+
+    assert_eq!(
+        receiver.receive(&headers, EVENT.as_bytes()).await,
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+
+    let events = events.0.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["message"], "webhook handler failed");
+    assert_eq!(events[0]["event_id"], "evt_01");
+    assert_eq!(events[0]["event_type"], "subscription.updated");
+    assert_eq!(events[0]["error"], "database unavailable");
+}
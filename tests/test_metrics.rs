@@ -0,0 +1,117 @@
+mod config;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::Router;
+use paddle_api::entities::product::list::ListProductsParams;
+use paddle_api::metrics::{MetricsSink, RequestMetrics};
+use paddle_api::retry::RetryPolicy;
+use paddle_api::Client;
+
+// (method, endpoint, status, retries)
+type Metrics = (String, String, Option<u16>, u32);
+
+#[derive(Clone, Default)]
+struct Recorded(Arc<Mutex<Vec<Metrics>>>);
+
+impl MetricsSink for Recorded {
+    fn record(&self, metrics: &RequestMetrics<'_>) {
+        self.0.lock().unwrap().push((
+            metrics.method().to_string(),
+            metrics.endpoint().to_string(),
+            metrics.status().map(|s| s.as_u16()),
+            metrics.retries(),
+        ));
+    }
+}
+
+// `/products` fails once with 503, `/products/{id}` is not found
+async fn serve<S: MetricsSink + 'static>(sink: S) -> Client {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let app = Router::new()
+        .route(
+            "/products",
+            get(move || async move {
+                match hits.fetch_add(1, Ordering::SeqCst) {
+                    0 => (StatusCode::SERVICE_UNAVAILABLE, "Service Unavailable"),
+                    _ => (StatusCode::OK, config::PRODUCTS),
+                }
+            }),
+        )
+        .route(
+            "/products/{id}",
+            get(|| async { (StatusCode::NOT_FOUND, config::NOT_FOUND) }),
+        );
+    Client::builder(&config::serve(app).await, "pdl_sdbx_apikey")
+        .set_retry_policy(RetryPolicy::new().set_base_delay(Duration::from_millis(1)))
+        .set_metrics_sink(sink)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_metrics_sink_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let recorded = Recorded::default();
+    let client = serve(recorded.clone()).await;
+
+    client
+        .get_list_products(ListProductsParams::default())
+        .await?;
+    let _ = client
+        .clone()
+        .get_product::<Vec<_>, String>(&"pro_01gsz4t5hdjse780zja8vvr7jg".parse()?, None)
+        .await;
+
+    assert_eq!(
+        *recorded.0.lock().unwrap(),
+        [
+            ("GET".to_string(), "products".to_string(), Some(200), 1),
+            ("GET".to_string(), "products/{id}".to_string(), Some(404), 0),
+        ]
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "metrics")]
+#[tokio::test]
+async fn test_metrics_recorder_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+    use paddle_api::metrics::MetricsRecorder;
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    recorder.install()?;
+
+    let client = serve(MetricsRecorder).await;
+    client
+        .get_list_products(ListProductsParams::default())
+        .await?;
+
+    let metrics = snapshotter.snapshot().into_vec();
+    let value = |name: &str| {
+        metrics
+            .iter()
+            .find(|(key, ..)| key.key().name() == name)
+            .map(|(key, _, _, value)| (key.key().labels().count(), value))
+    };
+
+    assert!(matches!(
+        value("paddle_api_requests_total"),
+        Some((3, DebugValue::Counter(1)))
+    ));
+    assert!(matches!(
+        value("paddle_api_retries_total"),
+        Some((2, DebugValue::Counter(1)))
+    ));
+    assert!(matches!(
+        value("paddle_api_request_duration_seconds"),
+        Some((2, DebugValue::Histogram(h))) if h.len() == 1
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+#![cfg(feature = "mock-server")]
+
+use paddle_api::entities::{
+    price::{list::ListPricesParams, Price, UnitPrice},
+    product::{list::ListProductsParams, Product, ProductTaxCategory},
+    BaseListParamsGettersSetters, EntityBaseGettersSetters, EntityStatus, EntityType,
+};
+use paddle_api::error::ErrorCode;
+use paddle_api::id::ProductId;
+use paddle_api::mock::MockServer;
+use paddle_api::Client;
+
+// Three standard products with a price each, and one custom product
+async fn seeded() -> Result<(MockServer, Client, Vec<ProductId>), Box<dyn std::error::Error>> {
+    let mock = MockServer::start().await?;
+    let client = Client::new(&mock.url(), "pdl_sdbx_apikey")?;
+    let mut ids = Vec::new();
+
+    for name in ["Basic", "Pro", "Enterprise"] {
+        let product = client
+            .create_product(
+                Product::default()
+                    .set_name(name)
+                    .set_tax_category(ProductTaxCategory::Standard),
+            )
+            .await?;
+        let id = product.data().id().unwrap().clone();
+
+        client
+            .create_price(
+                Price::default()
+                    .set_product_id(id.clone())
+                    .set_description(format!("{} monthly", name))
+                    .set_unit_price(UnitPrice::new("1000", "USD")),
+            )
+            .await?;
+
+        ids.push(id);
+    }
+
+    client
+        .create_product(
+            Product::default()
+                .set_name("Custom")
+                .set_p_type(EntityType::Custom)
+                .set_tax_category(ProductTaxCategory::SaaS),
+        )
+        .await?;
+
+    Ok((mock, client, ids))
+}
+
+mod tests_mock_product {
+    use super::*;
+
+    #[tokio::test]
+    async fn t_0() -> Result<(), Box<dyn std::error::Error>> {
+        let (_mock, client, ids) = seeded().await?;
+
+        assert!(ids
+            .iter()
+            .all(|id| id.as_str().starts_with("pro_") && id.as_str().len() == 30));
+
+        let r = client.get_product::<Vec<_>, String>(&ids[1], None).await?;
+        assert_eq!(r.data().name(), Some("Pro"));
+        assert!(r.data().prices().is_none());
+        assert!(r.data().created_at().unwrap().as_str().ends_with('Z'));
+
+        let r = client.get_product(&ids[1], Some(vec!["prices"])).await?;
+        let prices = r.data().prices().unwrap();
+        assert_eq!(prices.len(), 1);
+        assert!(prices[0].id().unwrap().as_str().starts_with("pri_"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn t_1() -> Result<(), Box<dyn std::error::Error>> {
+        let (_mock, client, ids) = seeded().await?;
+
+        let r = client
+            .update_product(
+                &ids[0],
+                Product::default().set_status(EntityStatus::Archived),
+            )
+            .await?;
+        assert_eq!(r.data().status(), Some(&EntityStatus::Archived));
+        assert_eq!(r.data().name(), Some("Basic"));
+
+        let r = client
+            .get_list_products(ListProductsParams::default().set_status(vec![EntityStatus::Active]))
+            .await?;
+        assert_eq!(r.data().len(), 2);
+
+        let r = client
+            .get_list_products(ListProductsParams::default().set_p_type(EntityType::Custom))
+            .await?;
+        assert_eq!(r.data()[0].product().name(), Some("Custom"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn t_2() -> Result<(), Box<dyn std::error::Error>> {
+        let (_mock, client, ids) = seeded().await?;
+
+        let r = client
+            .get_list_products(ListProductsParams::default().set_per_page(2))
+            .await?;
+        let pagination = r.meta().pagination.as_ref().unwrap();
+        assert_eq!(r.data().len(), 2);
+        assert!(pagination.has_more);
+        assert_eq!(pagination.estimated_total, 3);
+
+        let products: Vec<_> = client
+            .stream_products(ListProductsParams::default().set_per_page(2))
+            .collect_all(None, |_, _| ())
+            .await?;
+        let names: Vec<_> = products.iter().map(|p| p.name().unwrap()).collect();
+        assert_eq!(names, ["Basic", "Pro", "Enterprise"]);
+
+        let r = client
+            .get_list_products(
+                ListProductsParams::default()
+                    .set_id(vec![ids[2].clone()])
+                    .set_include(vec!["prices"]),
+            )
+            .await?;
+        assert_eq!(r.data().len(), 1);
+        assert_eq!(r.data()[0].product().prices().unwrap().len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn t_3() -> Result<(), Box<dyn std::error::Error>> {
+        let (_mock, client, _) = seeded().await?;
+
+        let e = client
+            .get_product::<Vec<_>, String>(&"pro_01gsz4t5hdjse780zja8vvr7jg".parse()?, None)
+            .await
+            .unwrap_err();
+        assert!(e.is_not_found());
+        assert!(e.request_id().is_some());
+
+        let e = client
+            .create_product(Product::default().set_name("No tax category"))
+            .await
+            .unwrap_err();
+        let error = e.api_error().unwrap();
+        assert_eq!(error.code, ErrorCode::InvalidField);
+        assert_eq!(error.errors.as_ref().unwrap()[0].field, "tax_category");
+
+        Ok(())
+    }
+}
+
+mod tests_mock_price {
+    use super::*;
+
+    #[tokio::test]
+    async fn t_0() -> Result<(), Box<dyn std::error::Error>> {
+        let (_mock, client, ids) = seeded().await?;
+
+        let r = client.get_list_prices(ListPricesParams::default()).await?;
+        assert_eq!(r.data().len(), 3);
+
+        let price_id = r.data()[0].price().id().unwrap().clone();
+        let r = client
+            .update_price(
+                &price_id,
+                Price::default().set_status(EntityStatus::Archived),
+            )
+            .await?;
+        assert_eq!(r.data().status(), Some(&EntityStatus::Archived));
+        assert_eq!(r.data().product_id(), Some(&ids[0]));
+
+        let r = client.get_price(&price_id, Some(vec!["product"])).await?;
+        assert_eq!(r.data().product().unwrap().name(), Some("Basic"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn t_1() -> Result<(), Box<dyn std::error::Error>> {
+        let (mock, client, _) = seeded().await?;
+
+        let e = client
+            .create_price(
+                Price::default()
+                    .set_product_id("pro_01gsz4t5hdjse780zja8vvr7jg".parse()?)
+                    .set_description("Orphan")
+                    .set_unit_price(UnitPrice::new("1000", "USD")),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(e.code(), Some(&ErrorCode::InvalidField));
+
+        let e = Client::new(&mock.url(), "")?
+            .get_list_prices(ListPricesParams::default())
+            .await
+            .unwrap_err();
+        assert!(e.is_auth_error());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use paddle_api::entities::price::UnitPrice;
+use paddle_api::money::{CurrencyCode, Money};
+use paddle_api::Error;
+use rust_decimal::Decimal;
+
+#[test]
+fn test_money_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(CurrencyCode::JPY.minor_units(), 0);
+    assert_eq!(CurrencyCode::USD.minor_units(), 2);
+    assert_eq!(CurrencyCode::KWD.minor_units(), 3);
+
+    assert_eq!("EUR".parse::<CurrencyCode>()?, CurrencyCode::EUR);
+    assert_eq!(CurrencyCode::EUR.to_string(), "EUR");
+    assert!(matches!(
+        "usd".parse::<CurrencyCode>(),
+        Err(Error::Validation(_))
+    ));
+    assert_eq!(serde_json::to_string(&CurrencyCode::GBP)?, r#""GBP""#);
+
+    Ok(())
+}
+
+#[test]
+fn test_money_t_1() -> Result<(), Box<dyn std::error::Error>> {
+    let usd = Money::from_major(Decimal::new(1050, 2), CurrencyCode::USD)?;
+    assert_eq!(usd.amount(), 1050);
+    assert_eq!(usd.to_major(), Decimal::new(1050, 2));
+    assert_eq!(usd.to_string(), "10.50 USD");
+
+    let jpy = Money::from_major(Decimal::from(1050), CurrencyCode::JPY)?;
+    assert_eq!(jpy.amount(), 1050);
+    assert_eq!(jpy.to_string(), "1050 JPY");
+
+    let kwd = Money::from_major(Decimal::new(105, 1), CurrencyCode::KWD)?;
+    assert_eq!(kwd.amount(), 10500);
+    assert_eq!(kwd.to_string(), "10.500 KWD");
+
+    // More decimal places than the currency has
+    assert!(Money::from_major(Decimal::new(1, 1), CurrencyCode::JPY).is_err());
+    assert!(Money::from_major(Decimal::new(10501, 3), CurrencyCode::USD).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_money_t_2() {
+    let a = Money::new(1050, CurrencyCode::USD);
+    let b = Money::new(250, CurrencyCode::USD);
+
+    assert_eq!(a.checked_add(&b), Some(Money::new(1300, CurrencyCode::USD)));
+    assert_eq!(b.checked_sub(&a), Some(Money::new(-800, CurrencyCode::USD)));
+    assert_eq!(a.checked_mul(4), Some(Money::new(4200, CurrencyCode::USD)));
+
+    let eur = Money::new(250, CurrencyCode::EUR);
+    assert_eq!(a.checked_add(&eur), None);
+    assert_eq!(a.checked_sub(&eur), None);
+
+    let max = Money::new(i64::MAX, CurrencyCode::USD);
+    assert_eq!(max.checked_add(&b), None);
+    assert_eq!(max.checked_mul(2), None);
+}
+
+#[test]
+fn test_money_t_3() -> Result<(), Box<dyn std::error::Error>> {
+    let unit_price = UnitPrice::new("1050", "USD");
+    let money = Money::try_from(&unit_price)?;
+    assert_eq!(money, Money::new(1050, CurrencyCode::USD));
+
+    let unit_price = UnitPrice::from(money);
+    assert_eq!(unit_price.amount(), "1050");
+    assert_eq!(unit_price.currency_code(), "USD");
+
+    assert!(Money::try_from(UnitPrice::new("10.50", "USD")).is_err());
+    assert!(Money::try_from(UnitPrice::new("1050", "XXX")).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+mod config;
+
+use std::collections::HashMap;
+
+use axum::extract::{Path, Query};
+use axum::routing::get;
+use axum::{Json, Router};
+use futures::TryStreamExt;
+use paddle_api::entities::price::list::ListPricesParams;
+use paddle_api::entities::product::list::ListProductsParams;
+use paddle_api::entities::{BaseListParamsGettersSetters, EntityBaseGettersSetters};
+use paddle_api::{Client, Error};
+use serde_json::{json, Value};
+
+const TOTAL: usize = 5;
+
+// Serves `TOTAL` entities of `/{entity}` honoring `after` and `per_page`
+async fn list(
+    Path(entity): Path<String>,
+    Query(query): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    let prefix = if entity == "products" { "pro" } else { "pri" };
+    let ids: Vec<String> = (0..TOTAL).map(|i| format!("{prefix}_{i:02}")).collect();
+
+    let per_page = query.get("per_page").map_or(50, |p| p.parse().unwrap());
+    let start = query.get("after").map_or(0, |after| {
+        ids.iter().position(|id| id == after).unwrap() + 1
+    });
+    let page = &ids[start..TOTAL.min(start + per_page)];
+    let last = page.last().cloned().unwrap_or_default();
+
+    Json(json!({
+        "data": page.iter().map(|id| json!({ "id": id, "name": id })).collect::<Vec<_>>(),
+        "meta": {
+            "request_id": config::REQUEST_ID,
+            "pagination": {
+                "per_page": per_page,
+                "next": format!("https://sandbox-api.paddle.com/{entity}?after={last}&per_page={per_page}"),
+                "has_more": start + per_page < TOTAL,
+                "estimated_total": TOTAL
+            }
+        }
+    }))
+}
+
+async fn serve() -> Client {
+    let url = config::serve(Router::new().route("/{entity}", get(list))).await;
+
+    Client::new(&url, "pdl_sdbx_apikey").unwrap()
+}
+
+#[tokio::test]
+async fn test_stream_products_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let client = serve().await;
+    let mut stream = client.stream_products(ListProductsParams::default().set_per_page(2));
+
+    assert_eq!(stream.estimated_total(), None);
+
+    let mut ids = Vec::new();
+    while let Some(product) = stream.try_next().await? {
+        ids.push(product.id().unwrap().to_string());
+    }
+
+    assert_eq!(ids, ["pro_00", "pro_01", "pro_02", "pro_03", "pro_04"]);
+    assert_eq!(stream.estimated_total(), Some(TOTAL as i32));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_products_t_1() -> Result<(), Box<dyn std::error::Error>> {
+    // The next page starts after a price ID
+    let url = config::serve(Router::new().route(
+        "/products",
+        get(|| async {
+            Json(json!({
+                "data": [{ "id": "pro_00", "name": "pro_00" }],
+                "meta": {
+                    "request_id": config::REQUEST_ID,
+                    "pagination": {
+                        "per_page": 1,
+                        "next": "https://sandbox-api.paddle.com/products?after=pri_00",
+                        "has_more": true,
+                        "estimated_total": 2
+                    }
+                }
+            }))
+        }),
+    ))
+    .await;
+    let client = Client::new(&url, "pdl_sdbx_apikey")?;
+
+    let mut stream = client.stream_products(ListProductsParams::default());
+    assert!(matches!(stream.try_next().await, Err(Error::Validation(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_prices_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let client = serve().await;
+    let prices: Vec<_> = client
+        .stream_prices(ListPricesParams::default().set_per_page(3))
+        .try_collect()
+        .await?;
+
+    assert_eq!(prices.len(), TOTAL);
+    assert_eq!(prices[4].id().unwrap(), "pri_04");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_collect_all_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let client = serve().await;
+    let mut progress = Vec::new();
+
+    let products = client
+        .stream_products(ListProductsParams::default().set_per_page(2))
+        .collect_all(None, |collected, total| progress.push((collected, total)))
+        .await?;
+
+    assert_eq!(products.len(), TOTAL);
+    assert_eq!(progress, [(2, Some(5)), (4, Some(5)), (5, Some(5))]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_collect_all_t_1() -> Result<(), Box<dyn std::error::Error>> {
+    let client = serve().await;
+    let mut pages = 0;
+
+    let mut stream = client.stream_prices(ListPricesParams::default().set_per_page(2));
+    let first = stream.try_next().await?.unwrap();
+    let prices = stream.collect_all(Some(2), |_, _| pages += 1).await?;
+
+    assert_eq!(first.id().unwrap(), "pri_00");
+    assert_eq!(
+        prices.iter().map(|p| p.id().unwrap()).collect::<Vec<_>>(),
+        ["pri_01", "pri_02"]
+    );
+    assert_eq!(pages, 1);
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+mod config;
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use axum::extract::Query;
+use axum::routing::get;
+use axum::{Json, Router};
+use futures::StreamExt;
+use paddle_api::poller::{CheckpointStore, EventPoller, FileCheckpoint, MemoryCheckpoint};
+use paddle_api::Client;
+use serde_json::{json, Value};
+
+const EVENTS: [&str; 5] = ["evt_1", "evt_2", "evt_3", "evt_4", "evt_5"];
+
+// Serves `EVENTS` from `/events` with `after` and `per_page`
+async fn events(Query(query): Query<HashMap<String, String>>) -> Json<Value> {
+    let start = match query.get("after") {
+        Some(after) => EVENTS.iter().position(|id| id == after).unwrap() + 1,
+        None => 0,
+    };
+    let per_page: usize = query
+        .get("per_page")
+        .map(|p| p.parse().unwrap())
+        .unwrap_or(50);
+    let end = (start + per_page).min(EVENTS.len());
+
+    let data: Vec<_> = EVENTS[start..end]
+        .iter()
+        .map(|id| {
+            json!({
+                "event_id": id,
+                "event_type": "customer.updated",
+                "occurred_at": "2024-04-12T10:18:49.738972Z",
+                "data": { "id": "ctm_01" }
+            })
+        })
+        .collect();
+
+    Json(json!({
+        "data": data,
+        "meta": {
+            "request_id": "2c8e3e6b-0f0e-4bd2-a2a5-bd6a9d8e3c8a",
+            "pagination": {
+                "per_page": per_page,
+                "next": format!("/events?after={}", EVENTS[end.max(1) - 1]),
+                "has_more": end < EVENTS.len(),
+                "estimated_total": EVENTS.len()
+            }
+        }
+    }))
+}
+
+async fn client() -> Client {
+    let url = config::serve(Router::new().route("/events", get(events))).await;
+
+    Client::new(&url, "pdl_sdbx_apikey").unwrap()
+}
+
+#[tokio::test]
+async fn test_event_poller_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let client = client().await;
+    let path =
+        std::env::temp_dir().join(format!("paddle_api_poller_{}.cursor", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    // A sibling with the name of a temporary file is left alone
+    let sibling = path.with_extension("tmp");
+    std::fs::write(&sibling, "other")?;
+
+    let ids = EventPoller::new(&client, FileCheckpoint::new(&path))
+        .set_per_page(2)
+        .set_poll_interval(Duration::from_millis(10), Duration::from_millis(20))
+        .into_stream()
+        .take(5)
+        .map(|event| event.unwrap().event_id().to_string())
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(ids, EVENTS);
+    // The last event wasn't acknowledged by requesting the next one
+    assert_eq!(FileCheckpoint::new(&path).load()?.unwrap(), "evt_4");
+
+    let mut events = Box::pin(
+        EventPoller::new(&client, FileCheckpoint::new(&path))
+            .set_poll_interval(Duration::from_millis(10), Duration::from_millis(20))
+            .into_stream(),
+    );
+
+    assert_eq!(events.next().await.unwrap()?.event_id(), "evt_5");
+
+    // Caught up, the poller keeps waiting for new events
+    assert!(
+        tokio::time::timeout(Duration::from_millis(100), events.next())
+            .await
+            .is_err()
+    );
+
+    assert_eq!(std::fs::read_to_string(&sibling)?, "other");
+
+    std::fs::remove_file(&sibling)?;
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_event_poller_t_1() -> Result<(), Box<dyn std::error::Error>> {
+    let client = client().await;
+
+    let mut events = Box::pin(
+        EventPoller::new(&client, MemoryCheckpoint::starting_after("evt_3".parse()?)).into_stream(),
+    );
+
+    assert_eq!(events.next().await.unwrap()?.event_id(), "evt_4");
+    assert_eq!(events.next().await.unwrap()?.event_id(), "evt_5");
+    Ok(())
+}
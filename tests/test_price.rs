@@ -1,19 +1,20 @@
+#![cfg(feature = "mock-server")]
+
 mod config;
 
 use config::*;
 
 use paddle_api::entities::{
-    price::{list::ListPricesParams, Price},
+    price::{list::ListPricesParams, Price, UnitPrice},
     EntityBaseGettersSetters, EntityStatus,
 };
-use paddle_api::Client;
 
 #[tokio::test]
 async fn test_get_price_t_0() -> Result<(), Box<dyn std::error::Error>> {
-    let config = CONFIG.clone();
-    let client = Client::new(&config.url, &config.auth)?;
+    let mock = Mock::start().await?;
+    let client = &mock.client;
     let r = client
-        .get_price::<Vec<_>, String>(&config.price_id, None)
+        .get_price::<Vec<_>, String>(&mock.price_id, None)
         .await?;
 
     println!("Get price response: {:#?}", r);
@@ -23,18 +24,18 @@ async fn test_get_price_t_0() -> Result<(), Box<dyn std::error::Error>> {
 #[tokio::test]
 #[should_panic]
 async fn test_get_price_t_1() {
-    let config = Config::new().unwrap();
-    let client = Client::new(&config.url, &config.auth).unwrap();
+    let mock = Mock::start().await.unwrap();
+    let client = &mock.client;
     let _ = client
-        .get_price::<Vec<_>, String>("invalprice_id_price_id", None)
+        .get_price::<Vec<_>, String>(&"pri_invalid".parse().unwrap(), None)
         .await
         .unwrap();
 }
 
 #[tokio::test]
 async fn test_get_list_prices_t_0() -> Result<(), Box<dyn std::error::Error>> {
-    let config = CONFIG.clone();
-    let client = Client::new(&config.url, &config.auth)?;
+    let mock = Mock::start().await?;
+    let client = &mock.client;
     let r = client.get_list_prices(ListPricesParams::default()).await?;
 
     if r.data().is_empty() {
@@ -47,12 +48,11 @@ async fn test_get_list_prices_t_0() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_update_price_t_0() -> Result<(), Box<dyn std::error::Error>> {
-    let config = CONFIG.clone();
-    let client = Client::new(&config.url, &config.auth)?;
+    let mock = Mock::start().await?;
+    let client = &mock.client;
 
-    let price_id = &config.price_id;
+    let price_id = &mock.price_id;
 
     let r = client
         .update_price(price_id, Price::default().set_status(EntityStatus::Active))
@@ -73,12 +73,18 @@ async fn test_update_price_t_0() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_create_price_t_0() -> Result<(), Box<dyn std::error::Error>> {
-    let config = CONFIG.clone();
-    let client = Client::new(&config.url, &config.auth)?;
+    let mock = Mock::start().await?;
+    let client = &mock.client;
 
-    let r = client.create_price(Price::default()).await?;
+    let r = client
+        .create_price(
+            Price::default()
+                .set_product_id(mock.product_id().clone())
+                .set_description("Basic yearly")
+                .set_unit_price(UnitPrice::new("10000", "USD")),
+        )
+        .await?;
 
     println!("Create price response: {:#?}", r);
 
@@ -1,3 +1,5 @@
+#![cfg(feature = "mock-server")]
+
 mod config;
 
 use config::*;
@@ -6,17 +8,16 @@ use paddle_api::entities::{
     product::{list::ListProductsParams, Product, ProductTaxCategory},
     BaseListParamsGettersSetters, EntityBaseGettersSetters, EntityStatus, EntityType,
 };
-use paddle_api::Client;
 
 mod tests_get_product {
     use super::*;
 
     #[tokio::test]
     async fn t_0() -> Result<(), Box<dyn std::error::Error>> {
-        let config = CONFIG.clone();
-        let client = Client::new(&config.url, &config.auth)?;
+        let mock = Mock::start().await?;
+        let client = &mock.client;
         let r = client
-            .get_product::<Vec<_>, String>(&config.product_id, None)
+            .get_product::<Vec<_>, String>(mock.product_id(), None)
             .await?;
 
         println!("Get product response: {:#?}", r);
@@ -26,20 +27,20 @@ mod tests_get_product {
     #[tokio::test]
     #[should_panic]
     async fn t_1() {
-        let config = Config::new().unwrap();
-        let client = Client::new(&config.url, &config.auth).unwrap();
+        let mock = Mock::start().await.unwrap();
+        let client = &mock.client;
         let _ = client
-            .get_product::<Vec<_>, String>("invalid_id", None)
+            .get_product::<Vec<_>, String>(&"pro_invalid".parse().unwrap(), None)
             .await
             .unwrap();
     }
 
     #[tokio::test]
     async fn t_2() -> Result<(), Box<dyn std::error::Error>> {
-        let config = CONFIG.clone();
-        let client = Client::new(&config.url, &config.auth)?;
+        let mock = Mock::start().await?;
+        let client = &mock.client;
         let r = client
-            .get_product(&config.product_id, Some(vec!["prices", "X"]))
+            .get_product(mock.product_id(), Some(vec!["prices", "X"]))
             .await?;
 
         println!("Get product response: {:#?}", r);
@@ -50,10 +51,10 @@ mod tests_get_product {
 
     #[tokio::test]
     async fn t_3() -> Result<(), Box<dyn std::error::Error>> {
-        let config = CONFIG.clone();
-        let client = Client::new(&config.url, &config.auth)?;
+        let mock = Mock::start().await?;
+        let client = &mock.client;
         let r = client
-            .get_product::<Vec<_>, String>(&config.product_id, None)
+            .get_product::<Vec<_>, String>(mock.product_id(), None)
             .await?;
 
         println!("Get product response: {:#?}", r);
@@ -79,8 +80,8 @@ mod tests_get_list_products {
 
     #[tokio::test]
     async fn t_0() -> Result<(), Box<dyn std::error::Error>> {
-        let config = CONFIG.clone();
-        let client = Client::new(&config.url, &config.auth)?;
+        let mock = Mock::start().await?;
+        let client = &mock.client;
         let r = client
             .get_list_products(
                 ListProductsParams::default().set_tax_category(vec![ProductTaxCategory::Standard]),
@@ -96,8 +97,8 @@ mod tests_get_list_products {
 
     #[tokio::test]
     async fn t_1() -> Result<(), Box<dyn std::error::Error>> {
-        let config = CONFIG.clone();
-        let client = Client::new(&config.url, &config.auth)?;
+        let mock = Mock::start().await?;
+        let client = &mock.client;
         let r = client
             .get_list_products(ListProductsParams::default())
             .await?;
@@ -107,7 +108,7 @@ mod tests_get_list_products {
         let after = uri_with_after.split("after=").last().unwrap();
 
         let r = client
-            .get_list_products(ListProductsParams::default().set_after(after))
+            .get_list_products(ListProductsParams::default().set_after(after.parse()?))
             .await?;
 
         println!("Get list products response: {:#?}", r);
@@ -119,15 +120,12 @@ mod tests_get_list_products {
 
     #[tokio::test]
     async fn t_2() -> Result<(), Box<dyn std::error::Error>> {
-        let config = CONFIG.clone();
-        let client = Client::new(&config.url, &config.auth)?;
+        let mock = Mock::start().await?;
+        let client = &mock.client;
         let r = client
             .get_list_products(
                 ListProductsParams::default()
-                    .set_id(vec![
-                        "pro_01j7k6xbf7jctjpnx0pz20th7s",
-                        "pro_01jcgjpcbfpr4wa8f6zmxwqbng",
-                    ])
+                    .set_id(mock.product_ids[..2].to_vec())
                     .set_include(vec!["prices", "X"]),
             )
             .await?;
@@ -141,8 +139,8 @@ mod tests_get_list_products {
 
     #[tokio::test]
     async fn t_3() -> Result<(), Box<dyn std::error::Error>> {
-        let config = CONFIG.clone();
-        let client = Client::new(&config.url, &config.auth)?;
+        let mock = Mock::start().await?;
+        let client = &mock.client;
         let r = client
             .get_list_products(ListProductsParams::default().set_include(vec!["prices", "X"]))
             .await?;
@@ -156,8 +154,8 @@ mod tests_get_list_products {
 
     #[tokio::test]
     async fn t_4() -> Result<(), Box<dyn std::error::Error>> {
-        let config = CONFIG.clone();
-        let client = Client::new(&config.url, &config.auth)?;
+        let mock = Mock::start().await?;
+        let client = &mock.client;
         let r = client
             .get_list_products(
                 ListProductsParams::default()
@@ -191,8 +189,8 @@ mod tests_get_list_products {
 
     #[tokio::test]
     async fn t_5() -> Result<(), Box<dyn std::error::Error>> {
-        let config = CONFIG.clone();
-        let client = Client::new(&config.url, &config.auth)?;
+        let mock = Mock::start().await?;
+        let client = &mock.client;
         let r = client
             .get_list_products(ListProductsParams::default().set_p_type(EntityType::Custom))
             .await?;
@@ -206,8 +204,8 @@ mod tests_get_list_products {
 
     #[tokio::test]
     async fn t_6() -> Result<(), Box<dyn std::error::Error>> {
-        let config = CONFIG.clone();
-        let client = Client::new(&config.url, &config.auth)?;
+        let mock = Mock::start().await?;
+        let client = &mock.client;
         let r = client
             .get_list_products(ListProductsParams::default().set_per_page(1))
             .await?;
@@ -225,10 +223,10 @@ mod tests_update_prodcut {
 
     #[tokio::test]
     async fn t_0() -> Result<(), Box<dyn std::error::Error>> {
-        let config = CONFIG.clone();
-        let client = Client::new(&config.url, &config.auth)?;
+        let mock = Mock::start().await?;
+        let client = &mock.client;
 
-        let id = &config.product_id;
+        let id = mock.product_id();
 
         let r = client.get_product::<Vec<_>, String>(id, None).await?;
 
@@ -258,13 +256,16 @@ mod tests_update_prodcut {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_create_product_t_0() -> Result<(), Box<dyn std::error::Error>> {
-    let config = CONFIG.clone();
-    let client = Client::new(&config.url, &config.auth)?;
+    let mock = Mock::start().await?;
+    let client = &mock.client;
 
     let r = client
-        .create_product(Product::default().set_tax_category(ProductTaxCategory::Standard))
+        .create_product(
+            Product::default()
+                .set_name("Starter")
+                .set_tax_category(ProductTaxCategory::Standard),
+        )
         .await?;
 
     println!("Create product response: {:#?}", r);
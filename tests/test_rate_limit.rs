@@ -0,0 +1,59 @@
+mod config;
+
+use std::time::Duration;
+
+use axum::routing::get;
+use axum::Router;
+use paddle_api::entities::product::list::ListProductsParams;
+use paddle_api::rate_limit::{RateLimit, RateLimiter};
+use paddle_api::Client;
+use tokio::time::Instant;
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_t_0() {
+    let limiter = RateLimiter::new(RateLimit::new(10, Duration::from_millis(500)).set_burst(2));
+
+    assert_eq!(limiter.available(), 2);
+
+    let start = Instant::now();
+    limiter.acquire().await;
+    limiter.acquire().await;
+    assert_eq!(limiter.available(), 0);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    // 10 per 500 ms: one token every 50 ms
+    limiter.acquire().await;
+    assert!(start.elapsed() >= Duration::from_millis(50));
+    assert!(start.elapsed() < Duration::from_millis(100));
+    assert_eq!(limiter.available(), 0);
+
+    // The bucket refills up to the burst
+    tokio::time::advance(Duration::from_secs(1)).await;
+    assert_eq!(limiter.available(), 2);
+}
+
+#[tokio::test]
+async fn test_rate_limiter_t_1() -> Result<(), Box<dyn std::error::Error>> {
+    let url =
+        config::serve(Router::new().route("/products", get(|| async { config::PRODUCTS }))).await;
+
+    let client = Client::new(&url, "pdl_sdbx_apikey")?;
+    assert_eq!(client.rate_limit_budget(), None);
+
+    let client = Client::builder(&url, "pdl_sdbx_apikey")
+        .set_rate_limit(RateLimit::new(5, Duration::from_secs(3600)))
+        .build()?;
+    let clone = client.clone();
+
+    clone
+        .get_list_products(ListProductsParams::default())
+        .await?;
+    client
+        .get_list_products(ListProductsParams::default())
+        .await?;
+
+    // Clones share the budget
+    assert_eq!(client.rate_limit_budget(), Some(3));
+    assert_eq!(clone.rate_limit_budget(), Some(3));
+    Ok(())
+}
@@ -0,0 +1,190 @@
+mod config;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::get;
+use axum::Router;
+use futures::future::BoxFuture;
+use paddle_api::entities::product::{list::ListProductsParams, Product};
+use paddle_api::entities::BaseListParamsGettersSetters;
+use paddle_api::retry::RetryPolicy;
+use paddle_api::transport::{self, Transport};
+use paddle_api::{Client, Error};
+use reqwest::{Request, Response};
+
+#[derive(Clone, Default)]
+struct Hits {
+    get: Arc<AtomicUsize>,
+    post: Arc<AtomicUsize>,
+}
+
+// GET fails twice with 503, then succeeds. `?after=pro_retryN` answers 429 with `Retry-After: N`.
+async fn list(
+    State(hits): State<Hits>,
+    axum::extract::RawQuery(query): axum::extract::RawQuery,
+) -> (StatusCode, HeaderMap, &'static str) {
+    let hit = hits.get.fetch_add(1, Ordering::SeqCst);
+    let mut headers = HeaderMap::new();
+
+    if let Some(seconds) = query
+        .as_deref()
+        .and_then(|q| q.strip_prefix("after=pro_retry"))
+    {
+        headers.insert("retry-after", seconds.parse().unwrap());
+        return (StatusCode::TOO_MANY_REQUESTS, headers, "Too Many Requests");
+    }
+
+    match hit {
+        0 => {
+            headers.insert("retry-after", "0".parse().unwrap());
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                headers,
+                "Service Unavailable",
+            )
+        }
+        1 => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            headers,
+            "Service Unavailable",
+        ),
+        _ => (StatusCode::OK, headers, config::PRODUCTS),
+    }
+}
+
+async fn create(State(hits): State<Hits>) -> (StatusCode, &'static str) {
+    hits.post.fetch_add(1, Ordering::SeqCst);
+    (StatusCode::SERVICE_UNAVAILABLE, "Service Unavailable")
+}
+
+async fn serve(policy: Option<RetryPolicy>) -> (Client, Hits) {
+    let hits = Hits::default();
+    let url = config::serve(
+        Router::new()
+            .route("/products", get(list).post(create))
+            .with_state(hits.clone()),
+    )
+    .await;
+
+    let mut builder = Client::builder(&url, "pdl_sdbx_apikey");
+    if let Some(policy) = policy {
+        builder = builder.set_retry_policy(policy);
+    }
+
+    (builder.build().unwrap(), hits)
+}
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy::new()
+        .set_base_delay(Duration::from_millis(1))
+        .set_max_delay(Duration::from_secs(1))
+        .set_jitter(false)
+}
+
+#[tokio::test]
+async fn test_retry_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let (client, hits) = serve(Some(fast_policy())).await;
+
+    let r = client
+        .get_list_products(ListProductsParams::default())
+        .await?;
+
+    assert!(r.data().is_empty());
+    assert_eq!(hits.get.load(Ordering::SeqCst), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_t_1() {
+    let (client, hits) = serve(None).await;
+
+    let error = client
+        .get_list_products(ListProductsParams::default())
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+    assert_eq!(hits.get.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_retry_t_2() {
+    let (client, hits) = serve(Some(fast_policy())).await;
+
+    assert!(client.create_product(Product::default()).await.is_err());
+    assert_eq!(hits.post.load(Ordering::SeqCst), 1);
+
+    let (client, hits) = serve(Some(fast_policy().set_retry_non_idempotent(true))).await;
+
+    assert!(client.create_product(Product::default()).await.is_err());
+    assert_eq!(hits.post.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_retry_t_3() {
+    // `Retry-After` longer than the max `Retry-After`
+    let (client, hits) = serve(Some(fast_policy())).await;
+
+    let error = client
+        .get_list_products(ListProductsParams::default().set_after("pro_retry120".parse().unwrap()))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+    assert_eq!(hits.get.load(Ordering::SeqCst), 1);
+
+    // Short `Retry-After` is honored until attempts run out
+    let (client, hits) = serve(Some(fast_policy())).await;
+
+    assert!(client
+        .get_list_products(ListProductsParams::default().set_after("pro_retry0".parse().unwrap()))
+        .await
+        .is_err());
+    assert_eq!(hits.get.load(Ordering::SeqCst), 3);
+}
+
+// Rate limited once with `Retry-After: 60`, then succeeds
+#[derive(Clone, Default)]
+struct RateLimited(Arc<AtomicUsize>);
+
+impl Transport for RateLimited {
+    fn execute(&self, _: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        let response = match self.0.fetch_add(1, Ordering::SeqCst) {
+            0 => {
+                let mut response = http::Response::new(String::from("Too Many Requests"));
+                *response.status_mut() = StatusCode::TOO_MANY_REQUESTS;
+                response
+                    .headers_mut()
+                    .insert("retry-after", "60".parse().unwrap());
+                response.into()
+            }
+            _ => transport::response(StatusCode::OK, config::PRODUCTS),
+        };
+
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_retry_t_4() -> Result<(), Box<dyn std::error::Error>> {
+    // The default policy waits out Paddle's 60 s rate limit block
+    let transport = RateLimited::default();
+    let client = Client::builder("https://sandbox-api.paddle.com/", "pdl_sdbx_apikey")
+        .set_retry_policy(RetryPolicy::default())
+        .set_transport(transport.clone())
+        .build()?;
+
+    let start = tokio::time::Instant::now();
+    client
+        .get_list_products(ListProductsParams::default())
+        .await?;
+
+    assert_eq!(transport.0.load(Ordering::SeqCst), 2);
+    assert!(start.elapsed() >= Duration::from_secs(60));
+
+    Ok(())
+}
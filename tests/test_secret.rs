@@ -0,0 +1,53 @@
+mod config;
+
+use axum::http::HeaderMap;
+use axum::routing::get;
+use axum::{Json, Router};
+use paddle_api::entities::product::list::ListProductsParams;
+use paddle_api::{Client, Secret};
+use serde_json::{json, Value};
+
+const KEY: &str = "pdl_sdbx_apikey_01gtgztp8fy5jqgjtqsg6dq5qv";
+
+#[test]
+fn test_secret_t_0() {
+    let secret = Secret::new(KEY);
+
+    assert_eq!(format!("{}", secret), "[REDACTED]");
+    assert_eq!(format!("{:?}", secret), "Secret([REDACTED])");
+    assert_eq!(secret.expose(), KEY);
+}
+
+#[cfg(any(feature = "debug", feature = "logs"))]
+#[test]
+fn test_secret_t_1() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::new("https://sandbox-api.paddle.com/", KEY)?;
+
+    assert!(!format!("{:?}", client).contains(KEY));
+
+    Ok(())
+}
+
+// Echoes the `Authorization` header in `meta.request_id`
+async fn products(headers: HeaderMap) -> Json<Value> {
+    let authorization = headers.get("authorization").unwrap();
+
+    Json(json!({
+        "data": [],
+        "meta": { "request_id": authorization.to_str().unwrap() }
+    }))
+}
+
+#[tokio::test]
+async fn test_secret_t_2() -> Result<(), Box<dyn std::error::Error>> {
+    let url = config::serve(Router::new().route("/products", get(products))).await;
+
+    let client = Client::new(&url, KEY)?;
+    let r = client
+        .get_list_products(ListProductsParams::default())
+        .await?;
+
+    assert_eq!(r.meta().request_id(), format!("Bearer {}", KEY));
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use paddle_api::entities::{product::Product, EntityBaseGettersSetters};
+use paddle_api::timestamp::Timestamp;
+
+const PRODUCT: &str = r#"{"id":"pro_01h7zcgmdc6tmwtjehp3sh7azf","created_at":"2023-08-18T10:46:18.792661Z","updated_at":"2023-08-18T10:46:18.000100Z"}"#;
+
+#[test]
+fn test_timestamp_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let product: Product = serde_json::from_str(PRODUCT)?;
+
+    assert_eq!(product.created_at().unwrap(), "2023-08-18T10:46:18.792661Z");
+    assert_eq!(
+        product.updated_at().unwrap().as_str(),
+        "2023-08-18T10:46:18.000100Z"
+    );
+    assert_eq!(serde_json::to_string(&product)?, PRODUCT);
+
+    assert!(serde_json::from_str::<Timestamp>(r#""2023-08-18""#).is_err());
+    assert!(serde_json::from_str::<Product>(r#"{"created_at":"yesterday"}"#).is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_timestamp_t_1() -> Result<(), Box<dyn std::error::Error>> {
+    use chrono::{FixedOffset, TimeZone, Timelike, Utc};
+
+    let product: Product = serde_json::from_str(PRODUCT)?;
+
+    for timestamp in [product.created_at().unwrap(), product.updated_at().unwrap()] {
+        let datetime = timestamp.to_chrono();
+        assert_eq!(&Timestamp::from(datetime), timestamp);
+    }
+
+    let datetime = product.created_at().unwrap().to_chrono();
+    assert_eq!(datetime.nanosecond(), 792_661_000);
+
+    // Converted to UTC, truncated to microseconds
+    let datetime = FixedOffset::east_opt(2 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2023, 8, 18, 12, 46, 18)
+        .unwrap()
+        .with_nanosecond(792_661_999)
+        .unwrap();
+    assert_eq!(Timestamp::from(datetime), "2023-08-18T10:46:18.792661Z");
+    assert_eq!(
+        Timestamp::from(Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap()),
+        "2024-01-02T03:04:05.000000Z"
+    );
+
+    let offset: Timestamp = "2023-08-18T12:46:18.5+02:00".parse()?;
+    assert_eq!(
+        offset.to_chrono(),
+        Utc.with_ymd_and_hms(2023, 8, 18, 10, 46, 18).unwrap()
+            + chrono::Duration::milliseconds(500)
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_timestamp_t_2() -> Result<(), Box<dyn std::error::Error>> {
+    use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+    let product: Product = serde_json::from_str(PRODUCT)?;
+
+    for timestamp in [product.created_at().unwrap(), product.updated_at().unwrap()] {
+        let datetime = timestamp.to_time();
+        assert_eq!(&Timestamp::from(datetime), timestamp);
+    }
+
+    let datetime = product.created_at().unwrap().to_time();
+    assert_eq!(datetime.microsecond(), 792_661);
+    assert_eq!(datetime.offset(), UtcOffset::UTC);
+
+    // Converted to UTC, truncated to microseconds
+    let datetime = PrimitiveDateTime::new(
+        Date::from_calendar_date(2023, Month::August, 18)?,
+        Time::from_hms_nano(12, 46, 18, 792_661_999)?,
+    )
+    .assume_offset(UtcOffset::from_hms(2, 0, 0)?);
+    assert_eq!(Timestamp::from(datetime), "2023-08-18T10:46:18.792661Z");
+
+    let offset: Timestamp = "2023-08-18T12:46:18+02:00".parse()?;
+    assert_eq!(offset.to_time(), datetime.replace_nanosecond(0)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_timestamp_t_3() -> Result<(), Box<dyn std::error::Error>> {
+    let timestamp: Timestamp = "2023-08-18T10:46:18.792661Z".parse()?;
+    assert_eq!(timestamp.unix_timestamp(), 1_692_355_578);
+    assert_eq!(timestamp.subsec_nanos(), 792_661_000);
+
+    // Chronological, whatever the precision or offset
+    let parse = |ts: &str| ts.parse::<Timestamp>().unwrap();
+    assert!(parse("2023-08-18T10:46:18Z") < parse("2023-08-18T10:46:18.1Z"));
+    assert!(parse("2023-08-18T10:46:18.100001Z") < parse("2023-08-18T10:46:18.9Z"));
+    assert_eq!(
+        parse("2023-08-18T10:46:18Z"),
+        parse("2023-08-18T10:46:18.000000Z")
+    );
+    assert_eq!(
+        parse("2023-08-18T12:46:18+02:00"),
+        parse("2023-08-18T10:46:18Z")
+    );
+    assert!(parse("2023-08-18T11:46:18+02:00") < parse("2023-08-18T10:46:18Z"));
+    assert_eq!(parse("1969-12-31T23:59:59.5Z").unix_timestamp(), -1);
+
+    // Serialized as sent
+    let timestamp = parse("2023-08-18t12:46:18.1+02:00");
+    assert_eq!(
+        serde_json::to_string(&timestamp)?,
+        r#""2023-08-18t12:46:18.1+02:00""#
+    );
+
+    for invalid in [
+        "2023-08-18",
+        "2023-08-18T10:46:18",
+        "2023-08-18 10:46:18Z",
+        "2023-02-29T10:46:18Z",
+        "2023-08-18T24:00:00Z",
+        "2023-08-18T10:46:18.Z",
+        "2023-08-18T10:46:18+0200",
+        "2023-08-18T10:46:18Z ",
+    ] {
+        assert!(matches!(
+            invalid.parse::<Timestamp>(),
+            Err(paddle_api::Error::Validation(_))
+        ));
+    }
+    assert!("2024-02-29T10:46:18Z".parse::<Timestamp>().is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn test_timestamp_t_4() -> Result<(), Box<dyn std::error::Error>> {
+    // The offset can't move the instant out of years 0000 to 9999 UTC
+    for out_of_range in ["9999-12-31T23:59:59-01:00", "0000-01-01T00:00:00+00:01"] {
+        assert!(out_of_range.parse::<Timestamp>().is_err());
+    }
+
+    let last: Timestamp = "9999-12-31T23:59:59.999999Z".parse()?;
+    let first: Timestamp = "0000-01-01T01:00:00+01:00".parse()?;
+    assert_eq!(last.unix_timestamp(), 253_402_300_799);
+    assert_eq!(first.unix_timestamp(), -62_167_219_200);
+
+    #[cfg(feature = "chrono")]
+    {
+        assert_eq!(Timestamp::from(last.to_chrono()), last);
+        assert_eq!(Timestamp::from(first.to_chrono()), first);
+    }
+
+    #[cfg(feature = "time")]
+    {
+        assert_eq!(Timestamp::from(last.to_time()), last);
+        assert_eq!(Timestamp::from(first.to_time()), first);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+mod config;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use futures::future::BoxFuture;
+use paddle_api::entities::price::list::ListPricesParams;
+use paddle_api::entities::product::list::ListProductsParams;
+use paddle_api::entities::EntityBaseGettersSetters;
+use paddle_api::transport::{self, Transport};
+use paddle_api::{Client, Error};
+use reqwest::{Request, Response, StatusCode};
+
+// Canned JSON by path, records the requested URLs
+#[derive(Clone, Default)]
+struct InMemory {
+    responses: HashMap<&'static str, &'static str>,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl Transport for InMemory {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push(request.url().to_string());
+
+        let response = match self.responses.get(request.url().path()) {
+            Some(body) => transport::response(StatusCode::OK, *body),
+            None => transport::response(StatusCode::NOT_FOUND, config::NOT_FOUND),
+        };
+
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+#[tokio::test]
+async fn test_transport_t_0() -> Result<(), Box<dyn std::error::Error>> {
+    let transport = InMemory {
+        responses: HashMap::from([
+            (
+                "/products",
+                r#"{"data":[{"id":"pro_01","name":"Product"}],"meta":{"request_id":"1"}}"#,
+            ),
+            (
+                "/prices",
+                r#"{"data":[{"id":"pri_01","product_id":"pro_01"}],"meta":{"request_id":"2"}}"#,
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    let client = Client::builder("https://sandbox-api.paddle.com/", "pdl_sdbx_apikey")
+        .set_transport(transport.clone())
+        .build()?;
+
+    let products = client
+        .get_list_products(ListProductsParams::default())
+        .await?;
+    assert_eq!(products.data()[0].product().name(), Some("Product"));
+
+    let prices = client.get_list_prices(ListPricesParams::default()).await?;
+    assert_eq!(prices.meta().request_id(), "2");
+
+    let r = client
+        .get_product::<Vec<_>, String>(&"pro_01gsz4t5hdjse780zja8vvr7jg".parse()?, None)
+        .await;
+    assert!(r.unwrap_err().is_not_found());
+
+    assert_eq!(
+        transport.requests.lock().unwrap()[2],
+        "https://sandbox-api.paddle.com/products/pro_01gsz4t5hdjse780zja8vvr7jg"
+    );
+
+    Ok(())
+}
+
+struct Offline;
+
+impl Transport for Offline {
+    fn execute(&self, _: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        Box::pin(async { Err(Error::Validation("offline".to_string())) })
+    }
+}
+
+#[tokio::test]
+async fn test_transport_t_1() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::builder("https://sandbox-api.paddle.com/", "pdl_sdbx_apikey")
+        .set_transport(Offline)
+        .build()?;
+
+    let r = client
+        .get_list_products(ListProductsParams::default())
+        .await;
+    assert!(matches!(r, Err(Error::Validation(e)) if e == "offline"));
+
+    Ok(())
+}
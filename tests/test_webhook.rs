@@ -0,0 +1,448 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use paddle_api::entities::{EntityBaseGettersSetters, EntityStatus};
+use paddle_api::webhook::dedup::{
+    DedupKey, EventDeduplicator, FileDeduplicator, MemoryDeduplicator,
+};
+use paddle_api::webhook::event::{EventData, WebhookEvent};
+use paddle_api::webhook::ordering::{EventOrder, OrderingGuard};
+use paddle_api::webhook::{parse_event, sign, verify_signature, WebhookError, DEFAULT_TOLERANCE};
+
+const SECRET: &str = "pdl_ntfset_01gkpjp8bkm3tm53kdgkx6sms7_6h3qd3uFSi9YCD3OLYAShQI90XTI5vEI";
+const BODY: &[u8] = br#"{"event_id":"evt_01"}"#;
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+mod tests_verify_signature {
+    use super::*;
+
+    #[test]
+    fn t_0() {
+        // Signature computed outside of the crate
+        let header =
+            "ts=1671552777;h1=1adb9452569df756388335e6cd78fa672c3486786ee7438adcfa06145b045a99";
+
+        assert_eq!(
+            verify_signature(BODY, header, SECRET, Duration::from_secs(u64::MAX)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn t_1() {
+        let header = sign(BODY, SECRET, now());
+
+        assert_eq!(
+            verify_signature(BODY, &header, SECRET, DEFAULT_TOLERANCE),
+            Ok(())
+        );
+        assert_eq!(
+            verify_signature(
+                br#"{"event_id":"evt_02"}"#,
+                &header,
+                SECRET,
+                DEFAULT_TOLERANCE
+            ),
+            Err(WebhookError::InvalidSignature)
+        );
+        assert_eq!(
+            verify_signature(BODY, &header, "another_secret", DEFAULT_TOLERANCE),
+            Err(WebhookError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn t_2() {
+        let timestamp = now() - 60;
+        let header = sign(BODY, SECRET, timestamp);
+
+        assert!(matches!(
+            verify_signature(BODY, &header, SECRET, DEFAULT_TOLERANCE),
+            Err(WebhookError::TimestampOutOfTolerance { timestamp: ts, .. }) if ts == timestamp
+        ));
+        assert_eq!(
+            verify_signature(BODY, &header, SECRET, Duration::from_secs(120)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn t_3() {
+        for header in ["", "ts=1671552777", "h1=abc", "ts=abc;h1=abc"] {
+            assert_eq!(
+                verify_signature(BODY, header, SECRET, DEFAULT_TOLERANCE),
+                Err(WebhookError::MalformedHeader)
+            );
+        }
+    }
+
+    #[test]
+    fn t_4() {
+        // Secret rotation: one of the signatures is valid
+        let timestamp = now();
+        let valid = sign(BODY, SECRET, timestamp);
+        let h1 = valid.split("h1=").last().unwrap();
+        let header = format!("ts={};h1=deadbeef;h1={}", timestamp, h1);
+
+        assert_eq!(
+            verify_signature(BODY, &header, SECRET, DEFAULT_TOLERANCE),
+            Ok(())
+        );
+    }
+}
+
+mod tests_webhook_event {
+    use super::*;
+
+    #[test]
+    fn t_0() -> Result<(), Box<dyn std::error::Error>> {
+        let body = br#"{
+            "event_id": "evt_01h7zcgmdc6tmwtjehp3sh7azf",
+            "event_type": "product.created",
+            "occurred_at": "2023-08-18T10:46:18.792661Z",
+            "notification_id": "ntf_01h7zcgmgc4qaxwe3mnhrzf1ta",
+            "data": {
+                "id": "pro_01h7zcgmdc6tmwtjehp3sh7azf",
+                "name": "ChatApp Pro",
+                "tax_category": "standard",
+                "type": "standard",
+                "description": null,
+                "image_url": null,
+                "custom_data": null,
+                "status": "active",
+                "import_meta": null,
+                "created_at": "2023-08-18T10:46:18.792661Z",
+                "updated_at": "2023-08-18T10:46:18.792661Z"
+            }
+        }"#;
+
+        let event = parse_event(body, &sign(body, SECRET, now()), SECRET, DEFAULT_TOLERANCE)?;
+
+        assert_eq!(event.event_id(), "evt_01h7zcgmdc6tmwtjehp3sh7azf");
+        assert_eq!(
+            event.notification_id().unwrap(),
+            "ntf_01h7zcgmgc4qaxwe3mnhrzf1ta"
+        );
+        assert_eq!(event.occurred_at(), "2023-08-18T10:46:18.792661Z");
+        assert_eq!(event.event_type(), "product.created");
+
+        let EventData::ProductCreated(product) = event.data() else {
+            panic!("Expected product.created");
+        };
+
+        assert_eq!(product.id().unwrap(), "pro_01h7zcgmdc6tmwtjehp3sh7azf");
+        assert_eq!(product.name(), Some("ChatApp Pro"));
+        assert_eq!(product.status(), Some(&EntityStatus::Active));
+        Ok(())
+    }
+
+    #[test]
+    fn t_1() -> Result<(), Box<dyn std::error::Error>> {
+        let event = WebhookEvent::from_slice(
+            br#"{
+                "event_id": "evt_01h7zd2p6vbgrx2f1k0mx4nqx9",
+                "event_type": "price.updated",
+                "occurred_at": "2023-08-18T10:56:09.723118Z",
+                "notification_id": "ntf_01h7zd2p9fz5q6k5y8c5rwx2gq",
+                "data": {
+                    "id": "pri_01h7zd2p6vbgrx2f1k0mx4nqx9",
+                    "product_id": "pro_01h7zcgmdc6tmwtjehp3sh7azf",
+                    "description": "Monthly",
+                    "unit_price": { "amount": "1000", "currency_code": "USD" },
+                    "billing_cycle": { "frequency": 1, "interval": "month" },
+                    "status": "archived"
+                }
+            }"#,
+        )?;
+
+        let EventData::PriceUpdated(price) = event.into_data() else {
+            panic!("Expected price.updated");
+        };
+
+        assert_eq!(
+            price.product_id().unwrap(),
+            "pro_01h7zcgmdc6tmwtjehp3sh7azf"
+        );
+        assert_eq!(price.unit_price().map(|p| p.amount()), Some("1000"));
+        assert_eq!(price.status(), Some(&EntityStatus::Archived));
+        Ok(())
+    }
+
+    #[test]
+    fn t_2() -> Result<(), Box<dyn std::error::Error>> {
+        let event = WebhookEvent::from_slice(
+            br#"{
+                "event_id": "evt_01",
+                "event_type": "subscription.updated",
+                "occurred_at": "2023-08-18T10:56:09.723118Z",
+                "notification_id": "ntf_01",
+                "data": { "id": "sub_01", "status": "active" }
+            }"#,
+        )?;
+
+        assert!(
+            matches!(event.data(), EventData::SubscriptionUpdated(data) if data["id"] == "sub_01")
+        );
+
+        let event = WebhookEvent::from_slice(
+            br#"{
+                "event_id": "evt_02",
+                "event_type": "something.new",
+                "occurred_at": "2023-08-18T10:56:09.723118Z",
+                "data": { "id": "new_01" }
+            }"#,
+        )?;
+
+        assert_eq!(event.event_type(), "something.new");
+        assert_eq!(event.notification_id(), None);
+        assert!(matches!(event.data(), EventData::Other { data, .. } if data["id"] == "new_01"));
+
+        // IDs are checked against their prefix
+        let r = WebhookEvent::from_slice(
+            br#"{
+                "event_id": "ntf_02",
+                "event_type": "something.new",
+                "occurred_at": "2023-08-18T10:56:09.723118Z",
+                "data": {}
+            }"#,
+        );
+        assert!(matches!(r, Err(WebhookError::InvalidPayload(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn t_3() {
+        let body = br#"{"event_id":"evt_01"}"#;
+
+        assert!(matches!(
+            parse_event(body, &sign(body, SECRET, now()), SECRET, DEFAULT_TOLERANCE),
+            Err(WebhookError::InvalidPayload(_))
+        ));
+    }
+}
+
+mod tests_dedup {
+    use super::*;
+
+    #[test]
+    fn t_0() -> Result<(), Box<dyn std::error::Error>> {
+        let dedup = MemoryDeduplicator::new(2);
+
+        dedup.mark_processed("evt_01")?;
+        dedup.mark_processed("evt_02")?;
+        // `evt_01` becomes the most recently used key
+        assert!(dedup.is_processed("evt_01")?);
+
+        dedup.mark_processed("evt_03")?;
+
+        assert!(dedup.is_processed("evt_01")?);
+        assert!(!dedup.is_processed("evt_02")?);
+        assert!(dedup.is_processed("evt_03")?);
+        Ok(())
+    }
+
+    #[test]
+    fn t_1() -> Result<(), Box<dyn std::error::Error>> {
+        let path = std::env::temp_dir().join(format!("paddle_api_dedup_{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let dedup = FileDeduplicator::open(&path)?;
+            assert!(!dedup.is_processed("evt_01")?);
+            dedup.mark_processed("evt_01")?;
+            dedup.mark_processed("evt_01")?;
+            dedup.mark_processed("evt_02")?;
+        }
+
+        // Restart
+        let dedup = FileDeduplicator::open(&path)?;
+        assert!(dedup.is_processed("evt_01")?);
+        assert!(dedup.is_processed("evt_02")?);
+        assert!(!dedup.is_processed("evt_03")?);
+        assert_eq!(std::fs::read_to_string(&path)?, "evt_01\nevt_02\n");
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn t_2() -> Result<(), Box<dyn std::error::Error>> {
+        let event = WebhookEvent::from_slice(
+            br#"{
+                "event_id": "evt_01",
+                "event_type": "customer.created",
+                "occurred_at": "2023-08-18T10:56:09.723118Z",
+                "notification_id": "ntf_01",
+                "data": {}
+            }"#,
+        )?;
+
+        assert_eq!(DedupKey::EventId.key(&event), "evt_01");
+        assert_eq!(DedupKey::NotificationId.key(&event), "ntf_01");
+        Ok(())
+    }
+
+    #[test]
+    fn t_3() -> Result<(), Box<dyn std::error::Error>> {
+        let path =
+            std::env::temp_dir().join(format!("paddle_api_dedup_claim_{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let memory = MemoryDeduplicator::new(10);
+        let file = FileDeduplicator::open(&path)?;
+
+        for dedup in [&memory as &dyn EventDeduplicator, &file] {
+            // Only one of concurrent deliveries gets the claim
+            assert!(dedup.try_claim("evt_01")?);
+            assert!(!dedup.try_claim("evt_01")?);
+
+            // A failed delivery gives it back
+            dedup.release("evt_01")?;
+            assert!(dedup.try_claim("evt_01")?);
+
+            dedup.mark_processed("evt_01")?;
+            assert!(!dedup.try_claim("evt_01")?);
+            assert!(dedup.is_processed("evt_01")?);
+        }
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
+
+mod tests_ordering {
+    use super::*;
+
+    fn event(event_type: &str, occurred_at: &str, data: &str) -> WebhookEvent {
+        WebhookEvent::from_slice(
+            format!(
+                r#"{{
+                    "event_id": "evt_01",
+                    "event_type": "{}",
+                    "occurred_at": "{}",
+                    "data": {}
+                }}"#,
+                event_type, occurred_at, data
+            )
+            .as_bytes(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn t_0() {
+        let guard = OrderingGuard::new();
+
+        let newer = event(
+            "subscription.updated",
+            "2024-04-12T10:20:00.000000Z",
+            r#"{ "id": "sub_01", "updated_at": "2024-04-12T10:18:49.738972Z" }"#,
+        );
+        let older = event(
+            "subscription.updated",
+            "2024-04-12T10:21:00.000000Z",
+            r#"{ "id": "sub_01", "updated_at": "2024-04-12T10:18:49.7Z" }"#,
+        );
+
+        assert_eq!(newer.entity_id(), Some("sub_01"));
+        assert_eq!(guard.classify(&newer), EventOrder::Apply);
+        guard.record(&newer);
+
+        assert_eq!(guard.classify(&newer), EventOrder::Duplicate);
+        // `updated_at` wins over `occurred_at`
+        assert_eq!(guard.classify(&older), EventOrder::Stale);
+
+        // Recording an older state doesn't move the guard back
+        guard.record(&older);
+        assert_eq!(guard.classify(&newer), EventOrder::Duplicate);
+    }
+
+    #[test]
+    fn t_1() {
+        let guard = OrderingGuard::new();
+
+        let product = |updated_at: &str| {
+            event(
+                "product.updated",
+                "2024-04-12T10:20:00Z",
+                &format!(
+                    r#"{{ "id": "pro_01", "name": "Pro", "updated_at": "{}" }}"#,
+                    updated_at
+                ),
+            )
+        };
+
+        guard.record(&product("2024-04-12T10:18:49.738972Z"));
+
+        assert_eq!(
+            guard.classify(&product("2024-04-12T10:18:49.738973Z")),
+            EventOrder::Apply
+        );
+        assert_eq!(
+            guard.classify(&product("2024-04-12T10:18:49.738972Z")),
+            EventOrder::Duplicate
+        );
+        assert_eq!(
+            guard.classify(&product("2024-04-12T10:18:49Z")),
+            EventOrder::Stale
+        );
+        // Compared as instants, not as strings
+        assert_eq!(
+            guard.classify(&product("2024-04-12T10:18:49.738972000Z")),
+            EventOrder::Duplicate
+        );
+        assert_eq!(
+            guard.classify(&product("2024-04-12T12:18:49.8+02:00")),
+            EventOrder::Apply
+        );
+
+        // Other entities and events without an entity ID
+        assert_eq!(
+            guard.classify_entity(
+                "pro_02",
+                "product.updated",
+                &"2000-01-01T00:00:00Z".parse().unwrap()
+            ),
+            EventOrder::Apply
+        );
+        assert_eq!(
+            guard.classify(&event("report.created", "2000-01-01T00:00:00Z", "{}")),
+            EventOrder::Apply
+        );
+    }
+
+    #[test]
+    fn t_2() {
+        let guard = OrderingGuard::new();
+        let subscription = |event_type: &str| {
+            event(
+                event_type,
+                "2024-04-12T10:20:00Z",
+                r#"{ "id": "sub_01a", "updated_at": "2024-04-12T10:18:49.738972Z" }"#,
+            )
+        };
+
+        guard.record(&subscription("subscription.created"));
+
+        // Several event types for one change of the entity
+        assert_eq!(
+            guard.classify(&subscription("subscription.activated")),
+            EventOrder::Apply
+        );
+        guard.record(&subscription("subscription.activated"));
+
+        assert_eq!(
+            guard.classify(&subscription("subscription.created")),
+            EventOrder::Duplicate
+        );
+        assert_eq!(
+            guard.classify(&subscription("subscription.activated")),
+            EventOrder::Duplicate
+        );
+    }
+}
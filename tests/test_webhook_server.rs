@@ -0,0 +1,235 @@
+#![cfg(feature = "webhook-server")]
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use axum::body::Body;
+use axum::http::{HeaderMap, Request, StatusCode};
+use paddle_api::webhook::dedup::{DedupKey, MemoryDeduplicator};
+use paddle_api::webhook::event::WebhookEvent;
+use paddle_api::webhook::ordering::OrderingGuard;
+use paddle_api::webhook::server::{WebhookHandler, WebhookReceiver};
+use paddle_api::webhook::{sign, SIGNATURE_HEADER};
+use tower::ServiceExt;
+
+const SECRET: &str = "pdl_ntfset_01gkpjp8bkm3tm53kdgkx6sms7_6h3qd3uFSi9YCD3OLYAShQI90XTI5vEI";
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn body(event_id: &str) -> String {
+    body_at(event_id, "2023-08-18T10:56:09.723118Z")
+}
+
+fn body_at(event_id: &str, occurred_at: &str) -> String {
+    body_of("subscription.updated", event_id, occurred_at)
+}
+
+fn body_of(event_type: &str, event_id: &str, occurred_at: &str) -> String {
+    format!(
+        r#"{{
+            "event_id": "{}",
+            "event_type": "{}",
+            "occurred_at": "{}",
+            "notification_id": "ntf_01",
+            "data": {{ "id": "sub_01" }}
+        }}"#,
+        event_id, event_type, occurred_at
+    )
+}
+
+fn request(body: &str, signature: Option<String>) -> Request<Body> {
+    let mut request = Request::post("/");
+
+    if let Some(signature) = signature {
+        request = request.header(SIGNATURE_HEADER, signature);
+    }
+
+    request.body(Body::from(body.to_string())).unwrap()
+}
+
+// Records received events, fails for `evt_fail`
+fn handler(received: Arc<Mutex<Vec<String>>>) -> WebhookReceiver<impl WebhookHandler> {
+    WebhookReceiver::new(SECRET, move |event: WebhookEvent| {
+        let received = received.clone();
+        async move {
+            if event.event_id() == "evt_fail" {
+                return Err("handler failed");
+            }
+            received.lock().unwrap().push(event.event_id().to_string());
+            Ok(())
+        }
+    })
+}
+
+fn receiver(received: Arc<Mutex<Vec<String>>>) -> axum::Router {
+    handler(received).into_router()
+}
+
+#[tokio::test]
+async fn test_webhook_server_t_0() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let body = body("evt_01");
+
+    let response = receiver(received.clone())
+        .oneshot(request(&body, Some(sign(body.as_bytes(), SECRET, now()))))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(*received.lock().unwrap(), vec!["evt_01"]);
+}
+
+#[tokio::test]
+async fn test_webhook_server_t_1() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let body = body("evt_01");
+
+    for signature in [None, Some(sign(body.as_bytes(), "another_secret", now()))] {
+        let response = receiver(received.clone())
+            .oneshot(request(&body, signature))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_webhook_server_t_2() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+
+    let body = r#"{"event_id":"evt_01"}"#;
+    let response = receiver(received.clone())
+        .oneshot(request(body, Some(sign(body.as_bytes(), SECRET, now()))))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = self::body("evt_fail");
+    let response = receiver(received.clone())
+        .oneshot(request(&body, Some(sign(body.as_bytes(), SECRET, now()))))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_webhook_server_t_3() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let router = handler(received.clone())
+        .set_deduplicator(MemoryDeduplicator::new(100), DedupKey::EventId)
+        .into_router();
+
+    for event_id in ["evt_01", "evt_02", "evt_01", "evt_fail", "evt_fail"] {
+        let body = body(event_id);
+        let expected = match event_id {
+            "evt_fail" => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::OK,
+        };
+
+        let response = router
+            .clone()
+            .oneshot(request(&body, Some(sign(body.as_bytes(), SECRET, now()))))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), expected);
+    }
+
+    assert_eq!(*received.lock().unwrap(), vec!["evt_01", "evt_02"]);
+}
+
+#[tokio::test]
+async fn test_webhook_server_t_4() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let router = handler(received.clone())
+        .set_ordering_guard(OrderingGuard::new())
+        .into_router();
+
+    for (event_id, occurred_at) in [
+        ("evt_01", "2023-08-18T10:56:09.000000Z"),
+        ("evt_03", "2023-08-18T10:58:00.000000Z"),
+        ("evt_02", "2023-08-18T10:57:00.000000Z"),
+        ("evt_04", "2023-08-18T10:58:00.000000Z"),
+    ] {
+        let body = body_at(event_id, occurred_at);
+        let response = router
+            .clone()
+            .oneshot(request(&body, Some(sign(body.as_bytes(), SECRET, now()))))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    assert_eq!(*received.lock().unwrap(), vec!["evt_01", "evt_03"]);
+}
+
+#[tokio::test]
+async fn test_webhook_server_t_6() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let router = handler(received.clone())
+        .set_ordering_guard(OrderingGuard::new())
+        .into_router();
+
+    // One change of the subscription, announced by two event types
+    for (event_type, event_id) in [
+        ("subscription.created", "evt_01"),
+        ("subscription.activated", "evt_02"),
+        ("subscription.created", "evt_03"),
+    ] {
+        let body = body_of(event_type, event_id, "2023-08-18T10:56:09.723118Z");
+        let response = router
+            .clone()
+            .oneshot(request(&body, Some(sign(body.as_bytes(), SECRET, now()))))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    assert_eq!(*received.lock().unwrap(), vec!["evt_01", "evt_02"]);
+}
+
+#[tokio::test]
+async fn test_webhook_server_t_5() {
+    // Concurrent deliveries of the same event, e.g. to two notification destinations
+    let calls = Arc::new(Mutex::new(0));
+    let receiver = WebhookReceiver::new(SECRET, {
+        let calls = calls.clone();
+        move |_: WebhookEvent| {
+            let calls = calls.clone();
+            async move {
+                *calls.lock().unwrap() += 1;
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok::<_, std::io::Error>(())
+            }
+        }
+    })
+    .set_deduplicator(MemoryDeduplicator::new(100), DedupKey::EventId);
+
+    let body = body("evt_01");
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        SIGNATURE_HEADER,
+        sign(body.as_bytes(), SECRET, now()).parse().unwrap(),
+    );
+
+    let (first, second) = tokio::join!(
+        receiver.receive(&headers, body.as_bytes()),
+        receiver.receive(&headers, body.as_bytes())
+    );
+
+    assert_eq!((first, second), (StatusCode::OK, StatusCode::OK));
+    assert_eq!(*calls.lock().unwrap(), 1);
+}